@@ -1,35 +1,115 @@
-use clap::Parser;
+use bytes::Bytes;
+use clap::{Parser, Subcommand};
 use futures::sink::SinkExt;
 use futures::stream::StreamExt;
-use spinel::{Command, Frame, HdlcCodec, Header, Property};
-use tokio_serial::{SerialPortBuilderExt, SerialStream};
+use spinel::codec::{decode_capability_list, Decode, HexDump, ProtocolVersion};
+use spinel::{
+    Command, DecodedItem, Error, FlowControl, Frame, HdlcPassthroughCodec, Header, LinkType,
+    PcapWriter, Property, PropertyStream, ResetLines, SerialConfig,
+};
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio_serial::{DataBits, Parity, SerialStream, StopBits};
 use tokio_util::codec::{Decoder, Framed};
 
+/// How long to wait for the device to answer a request.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
 struct SpinelHost {
-    stream: Framed<SerialStream, HdlcCodec>,
+    stream: Framed<SerialStream, HdlcPassthroughCodec>,
+    capture: Option<PcapWriter<File>>,
+    tid: u8,
 }
 
 impl SpinelHost {
-    async fn send_frame(&mut self, frame: Frame) {
-        self.stream.send(frame).await.unwrap();
+    /// Get the next Transaction Identifier (TID), skipping the reserved TID of `0`.
+    fn next_tid(&mut self) -> u8 {
+        self.tid = self.tid % 15 + 1;
+        self.tid
+    }
 
-        if let Some(resp) = self.stream.next().await {
-            match resp {
-                Ok(frame) => {
-                    println!("{:?}", frame);
-                }
-                Err(e) => {
-                    eprintln!("{:?}", e);
-                }
+    /// Send a frame without waiting for a response.
+    async fn send(&mut self, frame: Frame) -> Result<(), Error> {
+        self.stream.send(frame).await
+    }
+
+    /// Receive the next frame, writing it to the capture file if there is one.
+    ///
+    /// Frames that fail to decode are returned as errors without ending the stream, `None` is only returned once
+    /// the port itself has failed or closed.
+    async fn recv(&mut self) -> Option<Result<Frame, Error>> {
+        let frame = match self.stream.next().await? {
+            Ok(DecodedItem::Frame(frame)) => Ok(frame),
+            Ok(DecodedItem::Error { error, .. }) => Err(error),
+            Err(e) => Err(e),
+        };
+
+        if let (Ok(frame), Some(capture)) = (&frame, self.capture.as_mut()) {
+            if let Err(e) = capture.write_frame(frame) {
+                eprintln!("Capture error: {:?}", e);
             }
         }
+
+        Some(frame)
+    }
+
+    /// Send a command and wait for the response with the same TID.
+    ///
+    /// Frames received in the meantime, such as unsolicited broadcasts, are skipped.
+    async fn request(&mut self, command: Command) -> Result<Frame, Error> {
+        let tid = self.next_tid();
+        self.send(Frame::new(Header::new(0, tid), command)).await?;
+
+        let response = async {
+            while let Some(frame) = self.recv().await {
+                match frame {
+                    Ok(frame) if frame.header().tid() == tid => return Ok(frame),
+                    Ok(_) => {}
+                    Err(e) => eprintln!("{:?}", e),
+                }
+            }
+
+            Err(Error::from(io::Error::from(io::ErrorKind::UnexpectedEof)))
+        };
+
+        tokio::time::timeout(RESPONSE_TIMEOUT, response)
+            .await
+            .map_err(|_| Error::from(io::Error::from(io::ErrorKind::TimedOut)))?
+    }
+
+    /// Get the value of a property.
+    async fn get(&mut self, prop: Property) -> Result<Bytes, Error> {
+        let frame = self
+            .request(Command::PropertyValueGet(prop.clone()))
+            .await?;
+        expect_value(frame, &prop)
+    }
+
+    /// Set the value of a property, returning the value reported back by the device.
+    async fn set(&mut self, prop: Property, value: Bytes) -> Result<Bytes, Error> {
+        let frame = self
+            .request(Command::PropertyValueSet(prop.clone(), value))
+            .await?;
+        expect_value(frame, &prop)
     }
 
-    async fn recv_loop(&mut self) {
-        while let Some(frame) = self.stream.next().await {
+    /// Print every frame received, or only the frames of the given streams.
+    async fn monitor(&mut self, streams: &[PropertyStream]) {
+        while let Some(frame) = self.recv().await {
             match frame {
                 Ok(frame) => {
-                    println!("{:?}", frame);
+                    let wanted = match frame.stream_payload() {
+                        _ if streams.is_empty() => true,
+                        Some((stream, _)) => streams.contains(&stream),
+                        None => false,
+                    };
+
+                    if wanted {
+                        println!("{:?}", frame);
+                    }
                 }
                 Err(e) => {
                     eprintln!("{:?}", e);
@@ -37,6 +117,40 @@ impl SpinelHost {
             }
         }
     }
+
+    /// Print the firmware version, protocol version and capabilities of the device.
+    async fn info(&mut self) -> Result<(), Error> {
+        let version = self.get(Property::NcpVersion).await?;
+        let (version, _) = <&str>::decode(&version)?;
+        println!("Version: {}", version);
+
+        let protocol = self.get(Property::ProtocolVersion).await?;
+        let (protocol, _) = ProtocolVersion::decode(&protocol)?;
+        println!("Protocol: {}", protocol);
+
+        let caps = self.get(Property::Caps).await?;
+        println!("Capabilities:");
+        for cap in decode_capability_list(&caps) {
+            println!("  {:?}", cap?);
+        }
+
+        Ok(())
+    }
+}
+
+/// Extract the value of `prop` from a response, turning a [`Property::LastStatus`] into an error unless that is
+/// the property that was asked for.
+fn expect_value(frame: Frame, prop: &Property) -> Result<Bytes, Error> {
+    if *prop != Property::LastStatus {
+        if let Some(status) = frame.last_status() {
+            return Err(Error::Status(status?));
+        }
+    }
+
+    match frame.command() {
+        Command::PropertyValueIs(is_prop, value) if is_prop == *prop => Ok(value),
+        _ => Err(Error::UnexpectedResponse(frame)),
+    }
 }
 
 /// A CLI tool for interacting with a networking device using the Spinel protocol.
@@ -47,42 +161,259 @@ struct Args {
     #[clap(short('b'), long("baud"), default_value("115200"))]
     baud_rate: u32,
 
-    /// Flow control
-    #[clap(short('f'), long("flow-control"), default_value("None"))]
-    flow_control: Option<String>,
+    /// Flow control (none, software or hardware)
+    #[clap(short('f'), long("flow-control"), default_value("none"), value_parser = FlowControl::from_str)]
+    flow_control: FlowControl,
+
+    /// Data bits (5, 6, 7 or 8)
+    #[clap(long("data-bits"), value_parser = parse_data_bits)]
+    data_bits: Option<DataBits>,
+
+    /// Parity (none, odd or even)
+    #[clap(long("parity"), value_parser = parse_parity)]
+    parity: Option<Parity>,
+
+    /// Stop bits (1 or 2)
+    #[clap(long("stop-bits"), value_parser = parse_stop_bits)]
+    stop_bits: Option<StopBits>,
+
+    /// Control lines pulsed to reset the device before starting (none, dtr, rts or both)
+    #[clap(long("reset-lines"), default_value("none"), value_parser = ResetLines::from_str)]
+    reset_lines: ResetLines,
+
+    /// Send a software reset to the device before starting
+    #[clap(long("reset"))]
+    reset: bool,
 
     /// System port name
     #[clap(short('p'), long("port"))]
     port_name: String,
+
+    #[command(subcommand)]
+    action: Action,
 }
 
-#[tokio::main]
-async fn main() -> tokio_serial::Result<()> {
-    let args = Args::parse();
+#[derive(Subcommand, Debug)]
+enum Action {
+    /// Get the value of a property
+    Get {
+        /// Property name, for example ncp-version, or a numeric id such as 0x02
+        #[clap(value_parser = Property::from_str)]
+        property: Property,
+    },
+
+    /// Set the value of a property
+    Set {
+        /// Property name, for example net-if-up, or a numeric id such as 0x41
+        #[clap(value_parser = Property::from_str)]
+        property: Property,
 
-    let port_name = args.port_name;
-    let baud = args.baud_rate;
+        /// Value as hex bytes, for example 01 or e8030000
+        #[clap(value_parser = parse_hex)]
+        value: Bytes,
+    },
 
-    let port = tokio_serial::new(&port_name, baud).open_native_async()?;
-    let stream = HdlcCodec.framed(port);
+    /// Print frames received from the device
+    Monitor {
+        /// Only print these streams (debug, raw, net, net-insecure or log)
+        #[clap(value_parser = parse_stream)]
+        streams: Vec<PropertyStream>,
+    },
 
-    let mut host = SpinelHost { stream };
+    /// Print the firmware version, protocol version and capabilities
+    Info,
 
-    println!("Receiving data on {port_name} ({baud} baud)");
+    /// Write received network packets to a pcap file, printing frames like monitor until interrupted
+    Capture {
+        /// Path of the pcap file to create
+        path: PathBuf,
 
-    let reset_spinel_frame = spinel::Frame::new(Header::new(0, 0), Command::Reset);
-    host.send_frame(reset_spinel_frame).await;
+        /// Capture 802.15.4 frames from the raw stream instead of IPv6 packets from the net streams
+        #[clap(long("raw"))]
+        raw: bool,
+    },
+}
+
+fn parse_data_bits(s: &str) -> Result<DataBits, String> {
+    match s {
+        "5" => Ok(DataBits::Five),
+        "6" => Ok(DataBits::Six),
+        "7" => Ok(DataBits::Seven),
+        "8" => Ok(DataBits::Eight),
+        _ => Err(format!("invalid data bits: {s}")),
+    }
+}
+
+fn parse_parity(s: &str) -> Result<Parity, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "none" => Ok(Parity::None),
+        "odd" => Ok(Parity::Odd),
+        "even" => Ok(Parity::Even),
+        _ => Err(format!("invalid parity: {s}")),
+    }
+}
+
+fn parse_stop_bits(s: &str) -> Result<StopBits, String> {
+    match s {
+        "1" => Ok(StopBits::One),
+        "2" => Ok(StopBits::Two),
+        _ => Err(format!("invalid stop bits: {s}")),
+    }
+}
+
+fn parse_stream(s: &str) -> Result<PropertyStream, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "debug" => Ok(PropertyStream::Debug),
+        "raw" => Ok(PropertyStream::Raw),
+        "net" => Ok(PropertyStream::Net),
+        "net-insecure" => Ok(PropertyStream::NetInsecure),
+        "log" => Ok(PropertyStream::Log),
+        _ => Err(format!("invalid stream: {s}")),
+    }
+}
+
+fn parse_hex(s: &str) -> Result<Bytes, String> {
+    HexDump::parse(s)
+        .map(HexDump::into_bytes)
+        .ok_or_else(|| format!("invalid hex: {s}"))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), spinel::Error> {
+    let args = Args::parse();
+
+    let mut config = SerialConfig::new(&args.port_name, args.baud_rate)
+        .with_flow_control(args.flow_control)
+        .with_reset_lines(args.reset_lines);
+
+    if let Some(data_bits) = args.data_bits {
+        config = config.with_data_bits(data_bits);
+    }
+    if let Some(parity) = args.parity {
+        config = config.with_parity(parity);
+    }
+    if let Some(stop_bits) = args.stop_bits {
+        config = config.with_stop_bits(stop_bits);
+    }
+
+    let mut port = config.open()?;
+    config.hardware_reset(&mut port).await?;
+    let stream = HdlcPassthroughCodec::new().framed(port);
 
-    let noop_spinel_frame = spinel::Frame::new(Header::new(0, 2), Command::Noop);
-    host.send_frame(noop_spinel_frame.clone()).await;
+    let mut host = SpinelHost {
+        stream,
+        capture: None,
+        tid: 0,
+    };
 
-    let version_frame = spinel::Frame::new(
-        Header::new(0, 1),
-        Command::PropertyValueGet(Property::NcpVersion),
+    eprintln!(
+        "Connected to {} ({} baud, {:?} flow control)",
+        config.port_name, config.baud_rate, config.flow_control
     );
-    host.send_frame(version_frame).await;
 
-    host.recv_loop().await;
+    if args.reset {
+        host.send(Frame::new(Header::new(0, 0), Command::Reset))
+            .await?;
+    }
+
+    match args.action {
+        Action::Get { property } => {
+            let value = host.get(property.clone()).await?;
+            println!("{}: {:02x?}", property, &value[..]);
+        }
+        Action::Set { property, value } => {
+            let value = host.set(property.clone(), value).await?;
+            println!("{}: {:02x?}", property, &value[..]);
+        }
+        Action::Monitor { streams } => host.monitor(&streams).await,
+        Action::Info => host.info().await?,
+        Action::Capture { path, raw } => {
+            let link_type = if raw {
+                LinkType::Ieee802154
+            } else {
+                LinkType::Ipv6
+            };
+            host.capture = Some(PcapWriter::new(File::create(path)?, link_type)?);
+            host.monitor(&[]).await;
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_last_status_value() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(&[0x00])),
+        );
+
+        assert_eq!(
+            expect_value(frame.clone(), &Property::LastStatus),
+            Ok(Bytes::from_static(&[0x00]))
+        );
+        assert!(matches!(
+            expect_value(frame, &Property::NcpVersion),
+            Err(Error::Status(_))
+        ));
+    }
+
+    #[test]
+    fn parse_hex_values() {
+        assert_eq!(parse_hex("01"), Ok(Bytes::from_static(&[0x01])));
+        assert_eq!(
+            parse_hex("e8 03 00 00"),
+            Ok(Bytes::from_static(&[0xE8, 0x03, 0x00, 0x00]))
+        );
+        assert!(parse_hex("0").is_err());
+        assert!(parse_hex("zz").is_err());
+    }
+
+    #[test]
+    fn parse_stream_names() {
+        assert_eq!(
+            parse_stream("net-insecure"),
+            Ok(PropertyStream::NetInsecure)
+        );
+        assert_eq!(parse_stream("raw"), Ok(PropertyStream::Raw));
+        assert!(parse_stream("pcap").is_err());
+    }
+
+    #[test]
+    fn parse_subcommands() {
+        let args = Args::try_parse_from(["spinel-cli", "-p", "/dev/ttyACM0", "get", "ncp-version"])
+            .unwrap();
+        assert!(matches!(
+            args.action,
+            Action::Get {
+                property: Property::NcpVersion
+            }
+        ));
+
+        let args = Args::try_parse_from([
+            "spinel-cli",
+            "-p",
+            "/dev/ttyACM0",
+            "monitor",
+            "debug",
+            "log",
+        ])
+        .unwrap();
+        assert!(matches!(
+            args.action,
+            Action::Monitor { streams } if streams == [PropertyStream::Debug, PropertyStream::Log]
+        ));
+
+        let args =
+            Args::try_parse_from(["spinel-cli", "-p", "/dev/ttyACM0", "capture", "net.pcap"])
+                .unwrap();
+        assert!(matches!(
+            args.action,
+            Action::Capture { path, raw: false } if path.as_os_str() == "net.pcap"
+        ));
+    }
+}
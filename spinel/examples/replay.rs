@@ -0,0 +1,23 @@
+//! Print every frame in a recorded session.
+//!
+//! ```sh
+//! cargo run -p spinel --features test-util --example replay -- capture.txt
+//! ```
+use spinel::replay::ReplaySource;
+use std::fs::File;
+
+fn main() -> Result<(), spinel::Error> {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: replay <capture file>");
+        std::process::exit(2);
+    };
+
+    for frame in ReplaySource::new(File::open(path)?) {
+        match frame {
+            Ok(frame) => println!("{:?}", frame),
+            Err(e) => eprintln!("{:?}", e),
+        }
+    }
+
+    Ok(())
+}
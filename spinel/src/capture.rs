@@ -0,0 +1,225 @@
+//! Write packets received from a Spinel device to a libpcap capture file.
+use crate::{codec::NetStreamFrame, Error, Frame, PropertyStream};
+use std::io::Write;
+use std::time::{Duration, SystemTime};
+
+/// Link-layer header type written to the capture file.
+///
+/// Wireshark uses this to pick the dissector for every packet in the file, and it also decides which stream
+/// [`PcapWriter::write_frame`] captures. [`PropertyStream::Net`] and [`PropertyStream::NetInsecure`] carry IPv6
+/// packets with the 802.15.4 MAC header already removed, so they are written as [`LinkType::Ipv6`].
+/// [`PropertyStream::Raw`] carries the 802.15.4 frames themselves and is written as [`LinkType::Ieee802154`] or
+/// [`LinkType::Ieee802154NoFcs`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LinkType {
+    /// Raw IPv6 packets, as carried by [`PropertyStream::Net`] and [`PropertyStream::NetInsecure`].
+    #[default]
+    Ipv6,
+
+    /// IEEE 802.15.4 frames including the FCS, as carried by [`PropertyStream::Raw`].
+    Ieee802154,
+
+    /// IEEE 802.15.4 frames without the FCS, for devices that strip it from [`PropertyStream::Raw`].
+    Ieee802154NoFcs,
+}
+
+impl LinkType {
+    /// Check if packets carried by `stream` have this link type.
+    pub fn carries(&self, stream: &PropertyStream) -> bool {
+        match self {
+            LinkType::Ipv6 => matches!(stream, PropertyStream::Net | PropertyStream::NetInsecure),
+            LinkType::Ieee802154 | LinkType::Ieee802154NoFcs => *stream == PropertyStream::Raw,
+        }
+    }
+}
+
+impl From<LinkType> for u32 {
+    fn from(link_type: LinkType) -> Self {
+        match link_type {
+            LinkType::Ipv6 => 229,
+            LinkType::Ieee802154 => 195,
+            LinkType::Ieee802154NoFcs => 230,
+        }
+    }
+}
+
+/// Write packets to a libpcap capture file that can be opened with Wireshark.
+///
+/// The global header is written when the [`PcapWriter`] is created, each packet is then written with its own
+/// record header and timestamp. A capture file holds a single [`LinkType`], so [`PcapWriter::write_frame`] only
+/// writes frames from the streams that carry it.
+#[derive(Debug)]
+pub struct PcapWriter<W: Write> {
+    writer: W,
+    link_type: LinkType,
+}
+
+impl<W: Write> PcapWriter<W> {
+    const MAGIC: u32 = 0xA1B2_C3D4;
+    const VERSION_MAJOR: u16 = 2;
+    const VERSION_MINOR: u16 = 4;
+
+    /// Maximum number of bytes of each packet written to the file, longer packets are truncated.
+    pub const SNAPLEN: u32 = 65_535;
+
+    /// Create a new [`PcapWriter`] and write the global header for the link type.
+    pub fn new(mut writer: W, link_type: LinkType) -> Result<Self, Error> {
+        let mut header = Vec::with_capacity(24);
+        header.extend_from_slice(&Self::MAGIC.to_le_bytes());
+        header.extend_from_slice(&Self::VERSION_MAJOR.to_le_bytes());
+        header.extend_from_slice(&Self::VERSION_MINOR.to_le_bytes());
+        // Timezone offset and timestamp accuracy are always zero
+        header.extend_from_slice(&0i32.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&Self::SNAPLEN.to_le_bytes());
+        header.extend_from_slice(&u32::from(link_type).to_le_bytes());
+
+        writer.write_all(&header)?;
+
+        Ok(Self { writer, link_type })
+    }
+
+    /// Get the link type written in the global header.
+    pub fn link_type(&self) -> LinkType {
+        self.link_type
+    }
+
+    /// Write a packet with a timestamp measured from the Unix epoch.
+    pub fn write_packet(&mut self, timestamp: Duration, data: &[u8]) -> Result<(), Error> {
+        let orig_len = u32::try_from(data.len()).map_err(|_| Error::PacketLength(data.len()))?;
+        let incl_len = orig_len.min(Self::SNAPLEN);
+
+        let mut record = Vec::with_capacity(16 + incl_len as usize);
+        record.extend_from_slice(&(timestamp.as_secs() as u32).to_le_bytes());
+        record.extend_from_slice(&timestamp.subsec_micros().to_le_bytes());
+        record.extend_from_slice(&incl_len.to_le_bytes());
+        record.extend_from_slice(&orig_len.to_le_bytes());
+        record.extend_from_slice(&data[..incl_len as usize]);
+
+        self.writer.write_all(&record)?;
+
+        Ok(())
+    }
+
+    /// Write the packet carried by a stream [`Frame`], timestamped with the current time.
+    ///
+    /// Returns `false` without writing anything if the frame is not from a stream that carries the [`LinkType`] of
+    /// the file, see [`LinkType::carries`].
+    pub fn write_frame(&mut self, frame: &Frame) -> Result<bool, Error> {
+        let payload = match frame.stream_payload() {
+            Some((stream, payload)) if self.link_type.carries(&stream) => payload,
+            _ => return Ok(false),
+        };
+
+        let stream = NetStreamFrame::decode(&payload)?;
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        self.write_packet(timestamp, &stream.data)?;
+
+        Ok(true)
+    }
+
+    /// Flush any buffered packets to the underlying writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Consume the [`PcapWriter`] and return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Header, Property};
+    use bytes::Bytes;
+
+    #[test]
+    fn writes_global_header() {
+        let writer = PcapWriter::new(Vec::new(), LinkType::Ieee802154).unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            [
+                0xD4, 0xC3, 0xB2, 0xA1, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0xC3, 0x00, 0x00, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_one_packet() {
+        let mut writer = PcapWriter::new(Vec::new(), LinkType::Ipv6).unwrap();
+        writer
+            .write_packet(Duration::new(0x6000_0000, 1_500), &[0x60, 0x00, 0x00])
+            .unwrap();
+
+        let bytes = writer.into_inner();
+        assert_eq!(&bytes[20..24], &229u32.to_le_bytes());
+        assert_eq!(
+            &bytes[24..],
+            &[
+                0x00, 0x00, 0x00, 0x60, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+                0x00, 0x00, 0x60, 0x00, 0x00,
+            ]
+        );
+    }
+
+    /// A [`PropertyStream::Raw`] frame carrying a 5 byte 802.15.4 ack, FCS included.
+    fn raw_frame() -> Frame {
+        Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(
+                Property::Stream(PropertyStream::Raw),
+                Bytes::from_static(&[0x05, 0x00, 0x02, 0x00, 0x2A, 0x12, 0x34]),
+            ),
+        )
+    }
+
+    #[test]
+    fn writes_net_stream_frames_only() {
+        let mut writer = PcapWriter::new(Vec::new(), LinkType::Ipv6).unwrap();
+
+        // STREAM_NET (0x72) as received from the device
+        let net = Frame::decode(&Bytes::from_static(&[
+            0x80, 0x06, 0x72, 0x02, 0x00, 0x60, 0x00,
+        ]))
+        .unwrap();
+        let debug = Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(
+                Property::Stream(PropertyStream::Debug),
+                Bytes::from_static(b"hello"),
+            ),
+        );
+
+        assert_eq!(writer.write_frame(&net), Ok(true));
+        assert_eq!(writer.write_frame(&debug), Ok(false));
+        assert_eq!(writer.write_frame(&raw_frame()), Ok(false));
+
+        let bytes = writer.into_inner();
+        assert_eq!(bytes.len(), 24 + 16 + 2);
+        assert_eq!(&bytes[40..], &[0x60, 0x00]);
+    }
+
+    #[test]
+    fn writes_raw_stream_frames_as_802154() {
+        let mut writer = PcapWriter::new(Vec::new(), LinkType::Ieee802154).unwrap();
+
+        let net = Frame::decode(&Bytes::from_static(&[
+            0x80, 0x06, 0x72, 0x02, 0x00, 0x60, 0x00,
+        ]))
+        .unwrap();
+        assert_eq!(writer.write_frame(&net), Ok(false));
+        assert_eq!(writer.write_frame(&raw_frame()), Ok(true));
+
+        let bytes = writer.into_inner();
+        assert_eq!(&bytes[20..24], &195u32.to_le_bytes());
+        assert_eq!(bytes.len(), 24 + 16 + 5);
+        assert_eq!(&bytes[40..], &[0x02, 0x00, 0x2A, 0x12, 0x34]);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Error, Frame, HdlcLiteFrame};
+use bytes::{Buf, BufMut, BytesMut};
+
+/// Accumulate HDLC-lite encoded bytes and emit [`Frame`]s as they are completed.
+///
+/// This does not depend on `tokio` and can be used without `std`, for example when bytes are received one at a
+/// time from a UART interrupt. Reads of any size can be handed over with [`FrameAccumulator::push`] and the
+/// completed frames taken out with [`FrameAccumulator::next_frame`].
+#[derive(Debug, Default)]
+pub struct FrameAccumulator {
+    /// The frame currently being received, starting with its opening delimiter.
+    buffer: BytesMut,
+
+    /// Bytes passed to [`FrameAccumulator::push`] that have not been processed yet.
+    pending: BytesMut,
+}
+
+impl FrameAccumulator {
+    /// Create a new, empty [`FrameAccumulator`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a single byte into the accumulator.
+    ///
+    /// Returns the decoded [`Frame`] once its closing delimiter has been received, or the error if the completed
+    /// frame could not be decoded. Bytes received before the first delimiter are discarded.
+    ///
+    /// A frame that grows past [`HdlcLiteFrame::MAX_FRAME_LEN`] is discarded and returned as
+    /// [`Error::PacketLength`], the rest of it is then ignored until the next delimiter.
+    pub fn push_byte(&mut self, byte: u8) -> Option<Result<Frame, Error>> {
+        let is_delimiter = byte == HdlcLiteFrame::FRAME_DELIMITER_FLAG;
+
+        if !is_delimiter {
+            // Bytes outside of a frame are noise, wait for a delimiter to synchronize
+            if !self.buffer.is_empty() {
+                // The buffer holds the opening delimiter followed by the frame
+                if self.buffer.len() > HdlcLiteFrame::MAX_FRAME_LEN {
+                    self.buffer.clear();
+                    return Some(Err(Error::PacketLength(HdlcLiteFrame::MAX_FRAME_LEN + 1)));
+                }
+                self.buffer.put_u8(byte);
+            }
+            return None;
+        }
+
+        // Collapse runs of delimiters so idle lines don't grow the buffer
+        if self.buffer.len() <= 1 {
+            self.buffer.clear();
+            self.buffer.put_u8(byte);
+            return None;
+        }
+
+        self.buffer.put_u8(byte);
+        let frame = self.buffer.split().freeze();
+
+        // The closing delimiter also opens the next frame
+        self.buffer.put_u8(byte);
+
+        Some(HdlcLiteFrame::decode(&frame).map(HdlcLiteFrame::into_inner))
+    }
+
+    /// Push a chunk of received bytes into the accumulator.
+    ///
+    /// The bytes are buffered until they are processed by [`FrameAccumulator::next_frame`].
+    pub fn push(&mut self, data: &[u8]) {
+        self.pending.extend_from_slice(data);
+    }
+
+    /// Process pushed bytes until the next frame is completed.
+    ///
+    /// Returns `None` once all pushed bytes are processed without completing a frame. Any partial frame is kept
+    /// until more bytes are pushed.
+    pub fn next_frame(&mut self) -> Option<Result<Frame, Error>> {
+        while self.pending.has_remaining() {
+            let byte = self.pending.get_u8();
+            if let Some(result) = self.push_byte(byte) {
+                return Some(result);
+            }
+        }
+
+        None
+    }
+
+    /// Discard any partially received frame and unprocessed bytes.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Header};
+
+    const TEST_NOOP: [u8; 6] = [0x7e, 0x81, 0x00, 0x53, 0x9a, 0x7e];
+
+    fn push_all(accumulator: &mut FrameAccumulator, bytes: &[u8]) -> Vec<Result<Frame, Error>> {
+        bytes
+            .iter()
+            .filter_map(|byte| accumulator.push_byte(*byte))
+            .collect()
+    }
+
+    #[test]
+    fn emits_frame_on_closing_delimiter() {
+        let mut accumulator = FrameAccumulator::new();
+
+        for byte in &TEST_NOOP[..5] {
+            assert_eq!(accumulator.push_byte(*byte), None);
+        }
+
+        assert_eq!(
+            accumulator.push_byte(TEST_NOOP[5]),
+            Some(Ok(Frame::new(Header::new(0x00, 0x01), Command::Noop)))
+        );
+    }
+
+    #[test]
+    fn discards_leading_noise_and_idle_delimiters() {
+        let mut accumulator = FrameAccumulator::new();
+        let mut bytes = vec![0x01, 0x02, 0x7e, 0x7e, 0x7e];
+        bytes.extend_from_slice(&TEST_NOOP);
+
+        let frames = push_all(&mut accumulator, &bytes);
+        assert_eq!(
+            frames,
+            vec![Ok(Frame::new(Header::new(0x00, 0x01), Command::Noop))]
+        );
+    }
+
+    #[test]
+    fn decodes_frames_sharing_a_delimiter() {
+        let mut accumulator = FrameAccumulator::new();
+        let bytes = [
+            0x7e, 0x81, 0x00, 0x53, 0x9a, 0x7e, 0x81, 0x00, 0x53, 0x9a, 0x7e,
+        ];
+
+        let frames = push_all(&mut accumulator, &bytes);
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|frame| frame.is_ok()));
+    }
+
+    #[test]
+    fn decodes_frame_split_across_pushes() {
+        let mut accumulator = FrameAccumulator::new();
+
+        accumulator.push(&TEST_NOOP[..2]);
+        assert_eq!(accumulator.next_frame(), None);
+
+        accumulator.push(&TEST_NOOP[2..4]);
+        assert_eq!(accumulator.next_frame(), None);
+
+        accumulator.push(&TEST_NOOP[4..]);
+        assert_eq!(
+            accumulator.next_frame(),
+            Some(Ok(Frame::new(Header::new(0x00, 0x01), Command::Noop)))
+        );
+        assert_eq!(accumulator.next_frame(), None);
+    }
+
+    #[test]
+    fn next_frame_drains_multiple_frames() {
+        let mut accumulator = FrameAccumulator::new();
+        accumulator.push(&TEST_NOOP);
+        accumulator.push(&TEST_NOOP);
+
+        assert!(matches!(accumulator.next_frame(), Some(Ok(_))));
+        assert!(matches!(accumulator.next_frame(), Some(Ok(_))));
+        assert_eq!(accumulator.next_frame(), None);
+    }
+
+    #[test]
+    fn reports_corrupt_frame_and_recovers() {
+        let mut accumulator = FrameAccumulator::new();
+        let mut bytes = vec![0x7e, 0x81, 0x00, 0x00, 0x9a, 0x7e];
+        bytes.extend_from_slice(&TEST_NOOP);
+
+        let frames = push_all(&mut accumulator, &bytes);
+        assert_eq!(
+            frames,
+            vec![
+                Err(Error::HdlcChecksum(0x9A53)),
+                Ok(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+            ]
+        );
+    }
+
+    #[test]
+    fn discards_oversized_frame() {
+        let mut accumulator = FrameAccumulator::new();
+        let mut bytes = vec![0x7e];
+        bytes.resize(HdlcLiteFrame::MAX_FRAME_LEN + 8, 0x00);
+        bytes.extend_from_slice(&TEST_NOOP);
+
+        let frames = push_all(&mut accumulator, &bytes);
+        assert_eq!(
+            frames,
+            vec![
+                Err(Error::PacketLength(HdlcLiteFrame::MAX_FRAME_LEN + 1)),
+                Ok(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+            ]
+        );
+    }
+}
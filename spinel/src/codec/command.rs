@@ -1,11 +1,11 @@
 use crate::{
-    codec::{PackedU32, Property},
+    codec::{Encode, HostPowerState, PackedU32, Property, PropertyStream, Status},
     error::Error,
 };
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use core::fmt;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Command {
     /// No Operation
     ///
@@ -26,11 +26,83 @@ pub enum Command {
     /// of the property.
     PropertyValueGet(Property),
 
+    /// Set the value of a property
+    ///
+    /// The device will respond with [`Command::PropertyValueIs`](crate::Command::PropertyValueIs) containing the new
+    /// value of the property, or a [`Property::LastStatus`] if the property could not be set.
+    PropertyValueSet(Property, Bytes),
+
+    /// Insert a value into a list property
+    ///
+    /// The device will respond with [`Command::PropertyValueInserted`](crate::Command::PropertyValueInserted) once
+    /// the value has been added, or a [`Property::LastStatus`] if it could not be inserted.
+    PropertyValueInsert(Property, Bytes),
+
+    /// Remove a value from a list property
+    ///
+    /// The device will respond with [`Command::PropertyValueRemoved`](crate::Command::PropertyValueRemoved) once
+    /// the value has been removed, or a [`Property::LastStatus`] if it could not be removed.
+    PropertyValueRemove(Property, Bytes),
+
     /// Notification of the value of a property
     ///
     /// This command is typically sent in response to a [`Command::PropertyValueGet`](crate::Command::PropertyValueGet)
     /// command. However, it can also be sent by the device asyncronously to notify the host of a property value change.
     PropertyValueIs(Property, Bytes),
+
+    /// Notification that a value was inserted into a list property
+    ///
+    /// Sent in response to [`Command::PropertyValueInsert`](crate::Command::PropertyValueInsert), or asyncronously
+    /// when the device adds an item to a list property.
+    PropertyValueInserted(Property, Bytes),
+
+    /// Notification that a value was removed from a list property
+    ///
+    /// Sent in response to [`Command::PropertyValueRemove`](crate::Command::PropertyValueRemove), or asyncronously
+    /// when the device removes an item from a list property.
+    PropertyValueRemoved(Property, Bytes),
+
+    /// Save network state
+    ///
+    /// Ask the device to save the current network credentials to non-volatile storage, so that it can rejoin the
+    /// network after a reset. The device will respond with a [`Property::LastStatus`].
+    NetSave,
+
+    /// Clear saved network state
+    ///
+    /// Ask the device to erase the network credentials saved with [`Command::NetSave`](crate::Command::NetSave).
+    /// The device will respond with a [`Property::LastStatus`].
+    NetClear,
+
+    /// Recall saved network state
+    ///
+    /// Ask the device to load the network credentials saved with [`Command::NetSave`](crate::Command::NetSave).
+    /// The device will respond with a [`Property::LastStatus`].
+    NetRecall,
+
+    /// Read memory on the device
+    ///
+    /// Requests `count` bytes starting at `address`. The device will respond with
+    /// [`Command::PeekReturn`](crate::Command::PeekReturn) containing the memory, or a [`Property::LastStatus`] if
+    /// peeking is not supported. This is only intended for debugging firmware.
+    Peek { address: u32, count: u16 },
+
+    /// Memory read from the device
+    ///
+    /// Sent in response to [`Command::Peek`](crate::Command::Peek).
+    PeekReturn { address: u32, bytes: Bytes },
+
+    /// Write memory on the device
+    ///
+    /// Writes `bytes` starting at `address`. The device will respond with a [`Property::LastStatus`]. This is only
+    /// intended for debugging firmware.
+    Poke { address: u32, bytes: Bytes },
+
+    /// A vendor specific command
+    ///
+    /// Commands with an id in the vendor range (`0x3C00` to `0x3FFF`) are not interpreted, the payload following
+    /// the command id is kept as is.
+    Vendor(u32, Bytes),
 }
 
 impl fmt::Display for Command {
@@ -39,7 +111,25 @@ impl fmt::Display for Command {
             Command::Noop => write!(f, "Noop"),
             Command::Reset => write!(f, "Reset"),
             Command::PropertyValueGet(prop) => write!(f, "Get: {}", prop),
+            Command::PropertyValueSet(prop, value) => write!(f, "Set: {} {:?}", prop, value),
+            Command::PropertyValueInsert(prop, value) => write!(f, "Insert: {} {:?}", prop, value),
+            Command::PropertyValueRemove(prop, value) => write!(f, "Remove: {} {:?}", prop, value),
             Command::PropertyValueIs(prop, value) => write!(f, "Is: {} {:?}", prop, value),
+            Command::PropertyValueInserted(prop, value) => {
+                write!(f, "Inserted: {} {:?}", prop, value)
+            }
+            Command::PropertyValueRemoved(prop, value) => {
+                write!(f, "Removed: {} {:?}", prop, value)
+            }
+            Command::NetSave => write!(f, "NetSave"),
+            Command::NetClear => write!(f, "NetClear"),
+            Command::NetRecall => write!(f, "NetRecall"),
+            Command::Peek { address, count } => write!(f, "Peek: {:#x} {}", address, count),
+            Command::PeekReturn { address, bytes } => {
+                write!(f, "PeekReturn: {:#x} {:?}", address, bytes)
+            }
+            Command::Poke { address, bytes } => write!(f, "Poke: {:#x} {:?}", address, bytes),
+            Command::Vendor(id, value) => write!(f, "Vendor: {:#x} {:?}", id, value),
         }
     }
 }
@@ -48,8 +138,78 @@ impl Command {
     const CMD_NOOP: u32 = 0x00;
     const CMD_RESET: u32 = 0x01;
     const CMD_PROP_VALUE_GET: u32 = 0x02;
-    const _CMD_PROP_VALUE_SET: u32 = 0x03;
+    const CMD_PROP_VALUE_SET: u32 = 0x03;
+    const CMD_PROP_VALUE_INSERT: u32 = 0x04;
+    const CMD_PROP_VALUE_REMOVE: u32 = 0x05;
     const CMD_PROP_VALUE_IS: u32 = 0x06;
+    const CMD_PROP_VALUE_INSERTED: u32 = 0x07;
+    const CMD_PROP_VALUE_REMOVED: u32 = 0x08;
+    const CMD_NET_SAVE: u32 = 0x09;
+    const CMD_NET_CLEAR: u32 = 0x0A;
+    const CMD_NET_RECALL: u32 = 0x0B;
+    const CMD_PEEK: u32 = 0x12;
+    const CMD_PEEK_RET: u32 = 0x13;
+    const CMD_POKE: u32 = 0x14;
+    const CMD_VENDOR_BEGIN: u32 = 0x3C00;
+    const CMD_VENDOR_END: u32 = 0x4000;
+
+    /// Length of the `uint32` address and `uint16` count preceding the memory in peek/poke payloads.
+    const MEMORY_HEADER_LEN: usize = 6;
+
+    /// Create a [`Command::PropertyValueSet`] that sends a packet to the network on [`PropertyStream::Net`].
+    ///
+    /// The packet is wrapped in the stream datatype, a `uint16` length followed by the packet data. Returns
+    /// [`Error::PacketLength`] if the packet is too large for the length field.
+    pub fn stream_net_set(packet: &[u8]) -> Result<Self, Error> {
+        let len = u16::try_from(packet.len()).map_err(|_| Error::PacketLength(packet.len()))?;
+
+        let mut value = BytesMut::with_capacity(packet.len() + 2);
+        value.put_u16_le(len);
+        value.put_slice(packet);
+
+        Ok(Command::PropertyValueSet(
+            Property::Stream(PropertyStream::Net),
+            value.freeze(),
+        ))
+    }
+
+    /// Create a [`Command::PropertyValueSet`] that brings the network interface up or down.
+    pub fn set_interface_up(up: bool) -> Self {
+        Self::property_value_set_bool(Property::NetIfUp, up)
+    }
+
+    /// Create a [`Command::PropertyValueSet`] that starts or stops the Thread stack.
+    ///
+    /// The interface must be up, see [`Command::set_interface_up`], before the stack can be started.
+    pub fn set_stack_up(up: bool) -> Self {
+        Self::property_value_set_bool(Property::NetStackUp, up)
+    }
+
+    /// Create a [`Command::PropertyValueSet`] that declares the power state of the host to the device.
+    pub fn set_host_power_state(state: HostPowerState) -> Self {
+        let mut bytes = BytesMut::with_capacity(1);
+        state.encode(&mut bytes);
+        Command::PropertyValueSet(Property::HostPowerState, bytes.freeze())
+    }
+
+    /// Create a [`Command::PropertyValueSet`] that sets the data poll period of a sleepy end device in milliseconds.
+    pub fn set_poll_period(period_ms: u32) -> Self {
+        let mut bytes = BytesMut::with_capacity(4);
+        period_ms.encode(&mut bytes);
+        Command::PropertyValueSet(Property::MacDataPollPeriod, bytes.freeze())
+    }
+
+    /// Create a [`Command::PropertyValueSet`] that resets all counters on the device.
+    pub fn reset_counters() -> Self {
+        Command::PropertyValueSet(Property::CntrReset, Bytes::from_static(&[0x01]))
+    }
+
+    /// Create a [`Command::PropertyValueSet`] for a property holding a single `bool`.
+    fn property_value_set_bool(prop: Property, value: bool) -> Self {
+        let mut bytes = BytesMut::with_capacity(1);
+        value.encode(&mut bytes);
+        Command::PropertyValueSet(prop, bytes.freeze())
+    }
 
     /// Command identifier
     pub fn id(&self) -> u32 {
@@ -57,7 +217,19 @@ impl Command {
             Command::Noop => Self::CMD_NOOP,
             Command::Reset => Self::CMD_RESET,
             Command::PropertyValueGet(_) => Self::CMD_PROP_VALUE_GET,
+            Command::PropertyValueSet(_, _) => Self::CMD_PROP_VALUE_SET,
+            Command::PropertyValueInsert(_, _) => Self::CMD_PROP_VALUE_INSERT,
+            Command::PropertyValueRemove(_, _) => Self::CMD_PROP_VALUE_REMOVE,
             Command::PropertyValueIs(_, _) => Self::CMD_PROP_VALUE_IS,
+            Command::PropertyValueInserted(_, _) => Self::CMD_PROP_VALUE_INSERTED,
+            Command::PropertyValueRemoved(_, _) => Self::CMD_PROP_VALUE_REMOVED,
+            Command::NetSave => Self::CMD_NET_SAVE,
+            Command::NetClear => Self::CMD_NET_CLEAR,
+            Command::NetRecall => Self::CMD_NET_RECALL,
+            Command::Peek { .. } => Self::CMD_PEEK,
+            Command::PeekReturn { .. } => Self::CMD_PEEK_RET,
+            Command::Poke { .. } => Self::CMD_POKE,
+            Command::Vendor(id, _) => *id,
         }
     }
 
@@ -71,8 +243,19 @@ impl Command {
         match self {
             Command::Noop => 0,
             Command::Reset => 0,
+            Command::NetSave | Command::NetClear | Command::NetRecall => 0,
             Command::PropertyValueGet(prop) => prop.packed_len(),
-            Command::PropertyValueIs(prop, value) => prop.packed_len() + value.len(),
+            Command::PropertyValueSet(prop, value)
+            | Command::PropertyValueInsert(prop, value)
+            | Command::PropertyValueRemove(prop, value)
+            | Command::PropertyValueIs(prop, value)
+            | Command::PropertyValueInserted(prop, value)
+            | Command::PropertyValueRemoved(prop, value) => prop.packed_len() + value.len(),
+            Command::Peek { .. } => Self::MEMORY_HEADER_LEN,
+            Command::PeekReturn { bytes, .. } | Command::Poke { bytes, .. } => {
+                Self::MEMORY_HEADER_LEN + bytes.len()
+            }
+            Command::Vendor(_, value) => value.len(),
         }
     }
 
@@ -87,14 +270,47 @@ impl Command {
         let id = self.id();
 
         let _num = match self {
-            Command::Noop | Command::Reset => PackedU32::write_to_buffer(id, buffer),
+            Command::Noop
+            | Command::Reset
+            | Command::NetSave
+            | Command::NetClear
+            | Command::NetRecall => PackedU32::write_to_buffer(id, buffer),
             Command::PropertyValueGet(prop) => {
                 Self::write_to_buffer_with_property(id, prop, buffer)
             }
-            Command::PropertyValueIs(prop, value) => {
+            Command::PropertyValueSet(prop, value)
+            | Command::PropertyValueInsert(prop, value)
+            | Command::PropertyValueRemove(prop, value)
+            | Command::PropertyValueIs(prop, value)
+            | Command::PropertyValueInserted(prop, value)
+            | Command::PropertyValueRemoved(prop, value) => {
                 let num = Self::write_to_buffer_with_property(id, prop, buffer);
                 buffer.put_slice(value.as_ref());
 
+                num + value.len()
+            }
+            Command::Peek { address, count } => {
+                let num = PackedU32::write_to_buffer(id, buffer);
+                buffer.put_u32_le(address);
+                buffer.put_u16_le(count);
+
+                num + Self::MEMORY_HEADER_LEN
+            }
+            Command::PeekReturn { address, bytes } | Command::Poke { address, bytes } => {
+                let count =
+                    u16::try_from(bytes.len()).map_err(|_| Error::PacketLength(bytes.len()))?;
+
+                let num = PackedU32::write_to_buffer(id, buffer);
+                buffer.put_u32_le(address);
+                buffer.put_u16_le(count);
+                buffer.put_slice(bytes.as_ref());
+
+                num + Self::MEMORY_HEADER_LEN + bytes.len()
+            }
+            Command::Vendor(_, value) => {
+                let num = PackedU32::write_to_buffer(id, buffer);
+                buffer.put_slice(value.as_ref());
+
                 num + value.len()
             }
         };
@@ -115,29 +331,230 @@ impl Command {
 
     /// Decode the command from the buffer.
     pub fn decode(buffer: &Bytes) -> Result<Self, Error> {
+        CommandRef::decode(buffer.as_ref()).map(Command::from)
+    }
+
+    /// Split a trailing [`Status`] from the value of a [`Command::PropertyValueIs`].
+    ///
+    /// Some devices confirm a set by appending a packed `LastStatus` after the new value. Nothing on the wire marks
+    /// where the value ends, so this is only attempted for properties with a [`Property::fixed_len`]: any bytes
+    /// after that length are decoded as the status. [`Command::decode`] never does this itself, as a variable
+    /// length value cannot be told apart from one followed by a status.
+    ///
+    /// Returns the value and status, or `None` for other commands, properties without a fixed length, and values
+    /// with nothing after them. The status is [`Error::PacketLength`] if the trailing bytes are not a single packed
+    /// integer.
+    pub fn split_trailing_status(&self) -> Option<(Bytes, Result<Status, Error>)> {
+        let Command::PropertyValueIs(prop, value) = self else {
+            return None;
+        };
+
+        let len = prop.fixed_len()?;
+        if value.len() <= len {
+            return None;
+        }
+
+        let trailing = &value[len..];
+        let status = match PackedU32::decode_unbounded(trailing) {
+            Ok((code, count)) if count == trailing.len() => Ok(Status::from(code)),
+            _ => Err(Error::PacketLength(trailing.len())),
+        };
+
+        Some((value.slice(..len), status))
+    }
+}
+
+/// A [`Command`] that borrows its payload from the buffer it was decoded from.
+///
+/// Decoding into a [`CommandRef`] does not allocate, which suits targets without a heap. Convert it into an owned
+/// [`Command`] with [`From`] when the payload needs to outlive the buffer.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommandRef<'a> {
+    /// See [`Command::Noop`].
+    Noop,
+
+    /// See [`Command::Reset`].
+    Reset,
+
+    /// See [`Command::PropertyValueGet`].
+    PropertyValueGet(Property),
+
+    /// See [`Command::PropertyValueSet`].
+    PropertyValueSet(Property, &'a [u8]),
+
+    /// See [`Command::PropertyValueInsert`].
+    PropertyValueInsert(Property, &'a [u8]),
+
+    /// See [`Command::PropertyValueRemove`].
+    PropertyValueRemove(Property, &'a [u8]),
+
+    /// See [`Command::PropertyValueIs`].
+    PropertyValueIs(Property, &'a [u8]),
+
+    /// See [`Command::PropertyValueInserted`].
+    PropertyValueInserted(Property, &'a [u8]),
+
+    /// See [`Command::PropertyValueRemoved`].
+    PropertyValueRemoved(Property, &'a [u8]),
+
+    /// See [`Command::NetSave`].
+    NetSave,
+
+    /// See [`Command::NetClear`].
+    NetClear,
+
+    /// See [`Command::NetRecall`].
+    NetRecall,
+
+    /// See [`Command::Peek`].
+    Peek { address: u32, count: u16 },
+
+    /// See [`Command::PeekReturn`].
+    PeekReturn { address: u32, bytes: &'a [u8] },
+
+    /// See [`Command::Poke`].
+    Poke { address: u32, bytes: &'a [u8] },
+
+    /// See [`Command::Vendor`].
+    Vendor(u32, &'a [u8]),
+}
+
+impl<'a> CommandRef<'a> {
+    /// Decode the command from the buffer without copying the payload.
+    pub fn decode(buffer: &'a [u8]) -> Result<Self, Error> {
         if buffer.is_empty() {
             return Err(Error::PacketLength(0));
         }
 
-        let cmd_id_len = PackedU32::count_bytes(buffer.as_ref());
-        let id = PackedU32::decode(&buffer[..cmd_id_len]).0;
+        let (id, cmd_id_len) = PackedU32::decode_unbounded(buffer)?;
         let payload = &buffer[cmd_id_len..];
 
         match id {
-            Self::CMD_NOOP => Ok(Command::Noop),
-            Self::CMD_RESET => Ok(Command::Reset),
-            Self::CMD_PROP_VALUE_GET => {
+            Command::CMD_NOOP => Ok(CommandRef::Noop),
+            Command::CMD_RESET => Ok(CommandRef::Reset),
+            Command::CMD_PROP_VALUE_GET => {
                 let prop = Property::try_from(payload)?;
-                Ok(Command::PropertyValueGet(prop))
+                Ok(CommandRef::PropertyValueGet(prop))
             }
-            Self::CMD_PROP_VALUE_IS => {
-                let prop = Property::try_from(payload)?;
-                let value = Bytes::copy_from_slice(&payload[prop.packed_len()..]);
-                Ok(Command::PropertyValueIs(prop, value))
+            Command::CMD_PROP_VALUE_SET => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueSet(prop, value))
+            }
+            Command::CMD_PROP_VALUE_INSERT => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueInsert(prop, value))
+            }
+            Command::CMD_PROP_VALUE_REMOVE => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueRemove(prop, value))
+            }
+            Command::CMD_PROP_VALUE_IS => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueIs(prop, value))
+            }
+            Command::CMD_PROP_VALUE_INSERTED => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueInserted(prop, value))
+            }
+            Command::CMD_PROP_VALUE_REMOVED => {
+                let (prop, value) = Self::decode_property_value(payload)?;
+                Ok(CommandRef::PropertyValueRemoved(prop, value))
+            }
+            Command::CMD_NET_SAVE => Ok(CommandRef::NetSave),
+            Command::CMD_NET_CLEAR => Ok(CommandRef::NetClear),
+            Command::CMD_NET_RECALL => Ok(CommandRef::NetRecall),
+            Command::CMD_PEEK => {
+                let (address, count) = Self::decode_memory_header(payload)?;
+                Ok(CommandRef::Peek { address, count })
+            }
+            Command::CMD_PEEK_RET => {
+                let (address, bytes) = Self::decode_memory(payload)?;
+                Ok(CommandRef::PeekReturn { address, bytes })
+            }
+            Command::CMD_POKE => {
+                let (address, bytes) = Self::decode_memory(payload)?;
+                Ok(CommandRef::Poke { address, bytes })
+            }
+            Command::CMD_VENDOR_BEGIN..Command::CMD_VENDOR_END => {
+                Ok(CommandRef::Vendor(id, payload))
             }
             _ => Err(Error::Command(id)),
         }
     }
+
+    /// Decode a packed property ID followed by the property value.
+    fn decode_property_value(payload: &'a [u8]) -> Result<(Property, &'a [u8]), Error> {
+        let prop = Property::try_from(payload)?;
+
+        if payload.len() < prop.packed_len() {
+            return Err(Error::PacketLength(payload.len()));
+        }
+
+        let len = prop.packed_len();
+        Ok((prop, &payload[len..]))
+    }
+
+    /// Decode the `uint32` address and `uint16` count of a peek/poke payload.
+    fn decode_memory_header(mut payload: &[u8]) -> Result<(u32, u16), Error> {
+        if payload.len() < Command::MEMORY_HEADER_LEN {
+            return Err(Error::PacketLength(payload.len()));
+        }
+
+        Ok((payload.get_u32_le(), payload.get_u16_le()))
+    }
+
+    /// Decode the address, count and memory of a peek/poke payload.
+    fn decode_memory(payload: &'a [u8]) -> Result<(u32, &'a [u8]), Error> {
+        let (address, count) = Self::decode_memory_header(payload)?;
+        let memory = &payload[Command::MEMORY_HEADER_LEN..];
+
+        if memory.len() != usize::from(count) {
+            return Err(Error::PacketLength(payload.len()));
+        }
+
+        Ok((address, memory))
+    }
+}
+
+impl From<CommandRef<'_>> for Command {
+    fn from(cmd: CommandRef<'_>) -> Self {
+        match cmd {
+            CommandRef::Noop => Command::Noop,
+            CommandRef::Reset => Command::Reset,
+            CommandRef::PropertyValueGet(prop) => Command::PropertyValueGet(prop),
+            CommandRef::PropertyValueSet(prop, value) => {
+                Command::PropertyValueSet(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::PropertyValueInsert(prop, value) => {
+                Command::PropertyValueInsert(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::PropertyValueRemove(prop, value) => {
+                Command::PropertyValueRemove(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::PropertyValueIs(prop, value) => {
+                Command::PropertyValueIs(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::PropertyValueInserted(prop, value) => {
+                Command::PropertyValueInserted(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::PropertyValueRemoved(prop, value) => {
+                Command::PropertyValueRemoved(prop, Bytes::copy_from_slice(value))
+            }
+            CommandRef::NetSave => Command::NetSave,
+            CommandRef::NetClear => Command::NetClear,
+            CommandRef::NetRecall => Command::NetRecall,
+            CommandRef::Peek { address, count } => Command::Peek { address, count },
+            CommandRef::PeekReturn { address, bytes } => Command::PeekReturn {
+                address,
+                bytes: Bytes::copy_from_slice(bytes),
+            },
+            CommandRef::Poke { address, bytes } => Command::Poke {
+                address,
+                bytes: Bytes::copy_from_slice(bytes),
+            },
+            CommandRef::Vendor(id, value) => Command::Vendor(id, Bytes::copy_from_slice(value)),
+        }
+    }
 }
 
 impl TryFrom<Command> for Bytes {
@@ -158,6 +575,7 @@ mod tests {
     const TEST_CMD_NOOP_WIRE_FMT: [u8; 1] = [0x00];
     const TEST_CMD_RESET_WIRE_FMT: [u8; 1] = [0x01];
     const TEST_CMD_PROP_VALUE_GET_LAST_STATUS_WIRE_FMT: [u8; 2] = [0x02, 0x00];
+    const TEST_CMD_PROP_VALUE_SET_PROTOCOL_VERSION_WIRE_FMT: [u8; 4] = [0x03, 0x01, 0x04, 0x03];
 
     struct TestCmdArrayItem {
         /// Command enumeration
@@ -188,10 +606,20 @@ mod tests {
         bytes: &TEST_CMD_PROP_VALUE_GET_LAST_STATUS_WIRE_FMT,
     };
 
-    static TEST_CMD_ARRAY: [TestCmdArrayItem; 3] = [
+    const TEST_CMD_PROP_VALUE_SET_PROTOCOL_VERSION: TestCmdArrayItem = TestCmdArrayItem {
+        cmd: Command::PropertyValueSet(
+            Property::ProtocolVersion,
+            Bytes::from_static(&[0x04, 0x03]),
+        ),
+        len: 4,
+        bytes: &TEST_CMD_PROP_VALUE_SET_PROTOCOL_VERSION_WIRE_FMT,
+    };
+
+    static TEST_CMD_ARRAY: [TestCmdArrayItem; 4] = [
         TEST_CMD_NOOP,
         TEST_CMD_RESET,
         TEST_CMD_PROP_VALUE_GET_LAST_STATUS,
+        TEST_CMD_PROP_VALUE_SET_PROTOCOL_VERSION,
     ];
 
     /// Test all command lengths and byte arrays
@@ -220,6 +648,176 @@ mod tests {
         }
     }
 
+    #[test]
+    fn property_value_set_round_trip() {
+        let cmd = TEST_CMD_PROP_VALUE_SET_PROTOCOL_VERSION.cmd;
+        let bytes: Bytes = cmd.clone().try_into().unwrap();
+        assert_eq!(Command::decode(&bytes), Ok(cmd));
+    }
+
+    #[test]
+    fn insert_and_inserted_acknowledgement() {
+        let value = Bytes::from_static(&[0x18, 0xb4, 0x30, 0x00, 0x00, 0x3d, 0x2e, 0x4f]);
+
+        let insert = Command::PropertyValueInsert(Property::HardwareAddress, value.clone());
+        let bytes: Bytes = insert.clone().try_into().unwrap();
+        assert_eq!(&bytes[..2], &[0x04, 0x08]);
+        assert_eq!(&bytes[2..], &value[..]);
+        assert_eq!(Command::decode(&bytes), Ok(insert));
+
+        let mut ack = BytesMut::from(&[0x07, 0x08][..]);
+        ack.put_slice(&value);
+        assert_eq!(
+            Command::decode(&ack.freeze()),
+            Ok(Command::PropertyValueInserted(
+                Property::HardwareAddress,
+                value
+            ))
+        );
+    }
+
+    #[test]
+    fn remove_and_removed_round_trip() {
+        let value = Bytes::from_static(&[0x01]);
+
+        for cmd in [
+            Command::PropertyValueRemove(Property::InterfaceType, value.clone()),
+            Command::PropertyValueRemoved(Property::InterfaceType, value.clone()),
+        ] {
+            let bytes: Bytes = cmd.clone().try_into().unwrap();
+            assert_eq!(bytes.len(), cmd.total_packed_len());
+            assert_eq!(Command::decode(&bytes), Ok(cmd));
+        }
+    }
+
+    #[test]
+    fn stream_net_set_prefixes_length() {
+        let cmd = Command::stream_net_set(&[0x60, 0x00, 0x00]).unwrap();
+        let bytes: Bytes = cmd.try_into().unwrap();
+        assert_eq!(
+            bytes,
+            Bytes::from_static(&[0x03, 0x72, 0x03, 0x00, 0x60, 0x00, 0x00])
+        );
+    }
+
+    #[test]
+    fn stream_net_set_rejects_oversized_packet() {
+        let packet = [0; 0x1_0000];
+        assert_eq!(
+            Command::stream_net_set(&packet),
+            Err(Error::PacketLength(0x1_0000))
+        );
+    }
+
+    #[test]
+    fn split_trailing_status_after_fixed_length_value() {
+        let bytes = Bytes::from_static(&[0x06, 0x3A, 0xE8, 0x03, 0x00, 0x00, 0x00]);
+        let cmd = Command::decode(&bytes).unwrap();
+
+        // Decoding alone keeps the status as part of the value
+        assert_eq!(
+            cmd,
+            Command::PropertyValueIs(
+                Property::MacDataPollPeriod,
+                Bytes::from_static(&[0xE8, 0x03, 0x00, 0x00, 0x00])
+            )
+        );
+        assert_eq!(
+            cmd.split_trailing_status(),
+            Some((
+                Bytes::from_static(&[0xE8, 0x03, 0x00, 0x00]),
+                Ok(Status::Ok)
+            ))
+        );
+
+        let vendor =
+            Command::PropertyValueIs(Property::NetIfUp, Bytes::from_static(&[0x01, 0x80, 0x78]));
+        assert_eq!(
+            vendor.split_trailing_status(),
+            Some((Bytes::from_static(&[0x01]), Ok(Status::Vendor(0x3C00))))
+        );
+    }
+
+    #[test]
+    fn split_trailing_status_ignores_other_values() {
+        let without_status =
+            Command::PropertyValueIs(Property::NetIfUp, Bytes::from_static(&[0x01]));
+        assert_eq!(without_status.split_trailing_status(), None);
+
+        let variable_length =
+            Command::PropertyValueIs(Property::NcpVersion, Bytes::from_static(b"OT\0\0"));
+        assert_eq!(variable_length.split_trailing_status(), None);
+
+        let set = Command::PropertyValueSet(Property::NetIfUp, Bytes::from_static(&[0x01, 0x00]));
+        assert_eq!(set.split_trailing_status(), None);
+
+        let bogus = Command::PropertyValueIs(Property::NetIfUp, Bytes::from_static(&[0x01, 0x80]));
+        assert_eq!(
+            bogus.split_trailing_status(),
+            Some((Bytes::from_static(&[0x01]), Err(Error::PacketLength(1))))
+        );
+    }
+
+    #[test]
+    fn net_save_clear_recall() {
+        for (cmd, id) in [
+            (Command::NetSave, 0x09),
+            (Command::NetClear, 0x0A),
+            (Command::NetRecall, 0x0B),
+        ] {
+            let bytes: Bytes = cmd.clone().try_into().unwrap();
+            assert_eq!(bytes, Bytes::from(vec![id]));
+            assert_eq!(bytes.len(), cmd.total_packed_len());
+            assert_eq!(Command::decode(&bytes), Ok(cmd));
+        }
+    }
+
+    #[test]
+    fn set_interface_and_stack_up() {
+        let bytes: Bytes = Command::set_interface_up(true).try_into().unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x03, 0x41, 0x01]));
+
+        let bytes: Bytes = Command::set_stack_up(false).try_into().unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x03, 0x42, 0x00]));
+    }
+
+    #[test]
+    fn set_host_power_state() {
+        let bytes: Bytes = Command::set_host_power_state(HostPowerState::DeepSleep)
+            .try_into()
+            .unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x03, 0x0C, 0x01]));
+    }
+
+    #[test]
+    fn set_poll_period() {
+        let bytes: Bytes = Command::set_poll_period(240_000).try_into().unwrap();
+        assert_eq!(
+            bytes,
+            Bytes::from_static(&[0x03, 0x3A, 0x80, 0xA9, 0x03, 0x00])
+        );
+    }
+
+    #[test]
+    fn decode_poll_period() {
+        use crate::codec::Decode;
+
+        let bytes = Bytes::from_static(&[0x06, 0x3A, 0xE8, 0x03, 0x00, 0x00]);
+        let Ok(Command::PropertyValueIs(Property::MacDataPollPeriod, value)) =
+            Command::decode(&bytes)
+        else {
+            panic!("failed to decode poll period");
+        };
+
+        assert_eq!(u32::decode(&value), Ok((1000, 4)));
+    }
+
+    #[test]
+    fn reset_counters() {
+        let bytes: Bytes = Command::reset_counters().try_into().unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x03, 0x80, 0x0A, 0x01]));
+    }
+
     #[test]
     fn decode_fails_on_empty_buffer() {
         let cmd = Command::decode(&Bytes::new());
@@ -231,4 +829,110 @@ mod tests {
         let cmd = Command::decode(&Bytes::from_static(&[0xFF, 0xFF, 0x7F]));
         assert_eq!(cmd, Err(Error::Command(2_097_151)));
     }
+
+    #[test]
+    fn vendor_command_round_trip() {
+        let cmd = Command::Vendor(0x3C01, Bytes::from_static(&[0xAA, 0xBB]));
+        let bytes: Bytes = cmd.clone().try_into().unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x81, 0x78, 0xAA, 0xBB]));
+        assert_eq!(bytes.len(), cmd.total_packed_len());
+        assert_eq!(Command::decode(&bytes), Ok(cmd));
+    }
+
+    #[test]
+    fn vendor_property_round_trip() {
+        let cmd = Command::PropertyValueIs(Property::Vendor(0x3C00), Bytes::from_static(&[0x01]));
+        let bytes: Bytes = cmd.clone().try_into().unwrap();
+        assert_eq!(bytes, Bytes::from_static(&[0x06, 0x80, 0x78, 0x01]));
+        assert_eq!(Command::decode(&bytes), Ok(cmd));
+    }
+
+    #[test]
+    fn peek_and_poke_round_trip() {
+        let peek = Command::Peek {
+            address: 0x2000_0010,
+            count: 4,
+        };
+        let bytes: Bytes = peek.clone().try_into().unwrap();
+        assert_eq!(
+            bytes,
+            Bytes::from_static(&[0x12, 0x10, 0x00, 0x00, 0x20, 0x04, 0x00])
+        );
+        assert_eq!(bytes.len(), peek.total_packed_len());
+        assert_eq!(Command::decode(&bytes), Ok(peek));
+
+        let memory = Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        for cmd in [
+            Command::PeekReturn {
+                address: 0x2000_0010,
+                bytes: memory.clone(),
+            },
+            Command::Poke {
+                address: 0x2000_0010,
+                bytes: memory.clone(),
+            },
+        ] {
+            let bytes: Bytes = cmd.clone().try_into().unwrap();
+            assert_eq!(&bytes[1..7], &[0x10, 0x00, 0x00, 0x20, 0x04, 0x00]);
+            assert_eq!(&bytes[7..], &memory[..]);
+            assert_eq!(bytes.len(), cmd.total_packed_len());
+            assert_eq!(Command::decode(&bytes), Ok(cmd));
+        }
+    }
+
+    #[test]
+    fn decode_peek_return_count_mismatch() {
+        let cmd = Command::decode(&Bytes::from_static(&[
+            0x13, 0x00, 0x00, 0x00, 0x20, 0x04, 0x00, 0xDE, 0xAD,
+        ]));
+        assert_eq!(cmd, Err(Error::PacketLength(8)));
+
+        let cmd = Command::decode(&Bytes::from_static(&[0x12, 0x00, 0x00]));
+        assert_eq!(cmd, Err(Error::PacketLength(2)));
+    }
+
+    #[test]
+    fn decode_truncated_property() {
+        let cmd = Command::decode(&Bytes::from_static(&[Command::CMD_PROP_VALUE_IS as u8]));
+        assert_eq!(cmd, Err(Error::PacketLength(0)));
+
+        let cmd = Command::decode(&Bytes::from_static(&[
+            Command::CMD_PROP_VALUE_IS as u8,
+            0x88,
+        ]));
+        assert_eq!(cmd, Err(Error::PacketLength(1)));
+    }
+
+    #[test]
+    fn decode_four_byte_command_id() {
+        let cmd = Command::decode(&Bytes::from_static(&[0x80, 0x80, 0x80, 0x01]));
+        assert_eq!(cmd, Err(Error::Command(2_097_152)));
+    }
+
+    #[test]
+    fn commands_as_map_keys() {
+        use std::collections::HashMap;
+
+        let mut replies = HashMap::new();
+        replies.insert(Command::PropertyValueGet(Property::NcpVersion), "version");
+        replies.insert(
+            Command::PropertyValueSet(Property::PhyChannel, Bytes::from_static(&[11])),
+            "channel 11",
+        );
+
+        assert_eq!(
+            replies.get(&Command::PropertyValueSet(
+                Property::PhyChannel,
+                Bytes::from_static(&[11])
+            )),
+            Some(&"channel 11")
+        );
+        assert_eq!(
+            replies.get(&Command::PropertyValueSet(
+                Property::PhyChannel,
+                Bytes::from_static(&[12])
+            )),
+            None
+        );
+    }
 }
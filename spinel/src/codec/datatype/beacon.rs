@@ -0,0 +1,130 @@
+use super::{Decode, Eui64, InterfaceType};
+use crate::Error;
+
+/// A beacon received during an active scan, as reported by
+/// [`Property::MacScanBeacon`](crate::Property::MacScanBeacon).
+///
+/// On the wire this is `Cct(ESSC)t(iCUdd)`: the channel and RSSI, followed by a struct of MAC layer fields and a
+/// struct of network layer fields. Any trailing fields in either struct are skipped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeaconInfo<'a> {
+    /// The channel the beacon was received on.
+    pub channel: u8,
+
+    /// The RSSI of the beacon in dBm.
+    pub rssi: i8,
+
+    /// The extended address of the sender.
+    pub ext_addr: Eui64,
+
+    /// The short address of the sender.
+    pub short_addr: u16,
+
+    /// The PAN ID of the network.
+    pub pan_id: u16,
+
+    /// The link quality indicator of the beacon.
+    pub lqi: u8,
+
+    /// The network protocol advertised in the beacon.
+    pub protocol: InterfaceType,
+
+    /// Protocol specific flags, for Thread this includes whether joining is permitted.
+    pub flags: u8,
+
+    /// The name of the network.
+    pub network_name: &'a str,
+
+    /// The extended PAN ID of the network.
+    pub xpanid: &'a [u8],
+}
+
+impl<'a> Decode<'a> for BeaconInfo<'a> {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (channel, mut count) = u8::decode(bytes)?;
+
+        let (rssi, len) = i8::decode(&bytes[count..])?;
+        count += len;
+
+        // MAC layer struct
+        let (mac, len) = <&[u8]>::decode(&bytes[count..])?;
+        count += len;
+
+        let (ext_addr, mut offset) = Eui64::decode(mac)?;
+        let (short_addr, len) = u16::decode(&mac[offset..])?;
+        offset += len;
+        let (pan_id, len) = u16::decode(&mac[offset..])?;
+        offset += len;
+        let (lqi, _) = u8::decode(&mac[offset..])?;
+
+        // Network layer struct
+        let (net, len) = <&[u8]>::decode(&bytes[count..])?;
+        count += len;
+
+        let (protocol, mut offset) = InterfaceType::decode(net)?;
+        let (flags, len) = u8::decode(&net[offset..])?;
+        offset += len;
+        let (network_name, len) = <&str>::decode(&net[offset..])?;
+        offset += len;
+        let (xpanid, _) = <&[u8]>::decode(&net[offset..])?;
+
+        let beacon = Self {
+            channel,
+            rssi,
+            ext_addr,
+            short_addr,
+            pan_id,
+            lqi,
+            protocol,
+            flags,
+            network_name,
+            xpanid,
+        };
+
+        Ok((beacon, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_BEACON: [u8; 44] = [
+        // Channel, RSSI
+        0x0F, 0xC4, //
+        // MAC struct: extended address, short address, PAN ID, LQI
+        0x0D, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x34, 0x12, 0xCE, 0xFA, 0xFF,
+        // NET struct: protocol, flags, network name, XPANID, steering data
+        0x19, 0x00, 0x03, 0x01, b'O', b'p', b'e', b'n', b'T', b'h', b'r', b'e', b'a', b'd', 0x00,
+        0x08, 0x00, 0xDE, 0xAD, 0x00, 0xBE, 0xEF, 0x00, 0xCA, 0xFE, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn decode_beacon() {
+        let (beacon, count) = BeaconInfo::decode(&TEST_BEACON).unwrap();
+        assert_eq!(count, TEST_BEACON.len());
+        assert_eq!(
+            beacon,
+            BeaconInfo {
+                channel: 15,
+                rssi: -60,
+                ext_addr: Eui64([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]),
+                short_addr: 0x1234,
+                pan_id: 0xFACE,
+                lqi: 0xFF,
+                protocol: InterfaceType::Thread,
+                flags: 0x01,
+                network_name: "OpenThread",
+                xpanid: &[0xDE, 0xAD, 0x00, 0xBE, 0xEF, 0x00, 0xCA, 0xFE],
+            }
+        );
+    }
+
+    #[test]
+    fn decode_truncated_beacon() {
+        assert_eq!(
+            BeaconInfo::decode(&TEST_BEACON[..20]),
+            Err(Error::PacketLength(3))
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use super::PackedU32;
+use crate::Error;
+
+/// Capabilities reported by the device in [`Property::Caps`](crate::Property::Caps).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Capability {
+    /// The device supports the `LOCK` property.
+    Lock,
+
+    /// The device supports saving and recalling network credentials.
+    NetSave,
+
+    /// The device supports host buffer offload.
+    Hbo,
+
+    /// The device supports power saving.
+    PowerSave,
+
+    /// The device supports the counters properties.
+    Counters,
+
+    /// The device supports jamming detection.
+    JamDetect,
+
+    /// The device supports the peek and poke debug commands.
+    PeekPoke,
+
+    /// The raw stream can be written to.
+    WritableRawStream,
+
+    /// The device supports GPIO access.
+    Gpio,
+
+    /// The device supports the true random number generator.
+    Trng,
+
+    /// The device supports multiple commands in a single frame.
+    CmdMulti,
+
+    /// The device supports filtering unsolicited updates.
+    UnsolUpdateFilter,
+
+    /// The device supports the `MCU_POWER_STATE` property.
+    McuPowerState,
+
+    /// The device supports packet capture.
+    Pcap,
+
+    /// IEEE 802.15.4-2003 radio.
+    Ieee802154_2003,
+
+    /// IEEE 802.15.4-2006 radio.
+    Ieee802154_2006,
+
+    /// IEEE 802.15.4 O-QPSK radio on 2.4 GHz.
+    Ieee802154_2450MhzOqpsk,
+
+    /// IEEE 802.15.4 O-QPSK radio on 915 MHz.
+    Ieee802154_915MhzOqpsk,
+
+    /// IEEE 802.15.4 O-QPSK radio on 868 MHz.
+    Ieee802154_868MhzOqpsk,
+
+    /// Full Thread Device configuration.
+    ConfigFtd,
+
+    /// Minimal Thread Device configuration.
+    ConfigMtd,
+
+    /// Radio co-processor configuration.
+    ConfigRadio,
+
+    /// The device can act as a router.
+    RoleRouter,
+
+    /// The device can act as a sleepy end device.
+    RoleSleepy,
+
+    /// The device supports Thread 1.0.
+    NetThread1_0,
+
+    /// The device supports Thread 1.1.
+    NetThread1_1,
+
+    /// The device supports Thread 1.2.
+    NetThread1_2,
+
+    /// The device supports MAC allow listing.
+    MacAllowlist,
+
+    /// The device supports raw MAC frame access.
+    MacRaw,
+
+    /// A capability this crate does not recognize.
+    Unknown(u32),
+}
+
+impl Capability {
+    const CAP_LOCK: u32 = 1;
+    const CAP_NET_SAVE: u32 = 2;
+    const CAP_HBO: u32 = 3;
+    const CAP_POWER_SAVE: u32 = 4;
+    const CAP_COUNTERS: u32 = 5;
+    const CAP_JAM_DETECT: u32 = 6;
+    const CAP_PEEK_POKE: u32 = 7;
+    const CAP_WRITABLE_RAW_STREAM: u32 = 8;
+    const CAP_GPIO: u32 = 9;
+    const CAP_TRNG: u32 = 10;
+    const CAP_CMD_MULTI: u32 = 11;
+    const CAP_UNSOL_UPDATE_FILTER: u32 = 12;
+    const CAP_MCU_POWER_STATE: u32 = 13;
+    const CAP_PCAP: u32 = 14;
+    const CAP_802_15_4_2003: u32 = 16;
+    const CAP_802_15_4_2006: u32 = 17;
+    const CAP_802_15_4_2450MHZ_OQPSK: u32 = 18;
+    const CAP_802_15_4_915MHZ_OQPSK: u32 = 19;
+    const CAP_802_15_4_868MHZ_OQPSK: u32 = 20;
+    const CAP_CONFIG_FTD: u32 = 32;
+    const CAP_CONFIG_MTD: u32 = 33;
+    const CAP_CONFIG_RADIO: u32 = 34;
+    const CAP_ROLE_ROUTER: u32 = 48;
+    const CAP_ROLE_SLEEPY: u32 = 49;
+    const CAP_NET_THREAD_1_0: u32 = 52;
+    const CAP_NET_THREAD_1_1: u32 = 53;
+    const CAP_NET_THREAD_1_2: u32 = 54;
+    const CAP_MAC_ALLOWLIST: u32 = 512;
+    const CAP_MAC_RAW: u32 = 513;
+}
+
+impl From<u32> for Capability {
+    fn from(id: u32) -> Self {
+        match id {
+            Self::CAP_LOCK => Self::Lock,
+            Self::CAP_NET_SAVE => Self::NetSave,
+            Self::CAP_HBO => Self::Hbo,
+            Self::CAP_POWER_SAVE => Self::PowerSave,
+            Self::CAP_COUNTERS => Self::Counters,
+            Self::CAP_JAM_DETECT => Self::JamDetect,
+            Self::CAP_PEEK_POKE => Self::PeekPoke,
+            Self::CAP_WRITABLE_RAW_STREAM => Self::WritableRawStream,
+            Self::CAP_GPIO => Self::Gpio,
+            Self::CAP_TRNG => Self::Trng,
+            Self::CAP_CMD_MULTI => Self::CmdMulti,
+            Self::CAP_UNSOL_UPDATE_FILTER => Self::UnsolUpdateFilter,
+            Self::CAP_MCU_POWER_STATE => Self::McuPowerState,
+            Self::CAP_PCAP => Self::Pcap,
+            Self::CAP_802_15_4_2003 => Self::Ieee802154_2003,
+            Self::CAP_802_15_4_2006 => Self::Ieee802154_2006,
+            Self::CAP_802_15_4_2450MHZ_OQPSK => Self::Ieee802154_2450MhzOqpsk,
+            Self::CAP_802_15_4_915MHZ_OQPSK => Self::Ieee802154_915MhzOqpsk,
+            Self::CAP_802_15_4_868MHZ_OQPSK => Self::Ieee802154_868MhzOqpsk,
+            Self::CAP_CONFIG_FTD => Self::ConfigFtd,
+            Self::CAP_CONFIG_MTD => Self::ConfigMtd,
+            Self::CAP_CONFIG_RADIO => Self::ConfigRadio,
+            Self::CAP_ROLE_ROUTER => Self::RoleRouter,
+            Self::CAP_ROLE_SLEEPY => Self::RoleSleepy,
+            Self::CAP_NET_THREAD_1_0 => Self::NetThread1_0,
+            Self::CAP_NET_THREAD_1_1 => Self::NetThread1_1,
+            Self::CAP_NET_THREAD_1_2 => Self::NetThread1_2,
+            Self::CAP_MAC_ALLOWLIST => Self::MacAllowlist,
+            Self::CAP_MAC_RAW => Self::MacRaw,
+            _ => Self::Unknown(id),
+        }
+    }
+}
+
+impl From<Capability> for u32 {
+    fn from(cap: Capability) -> u32 {
+        match cap {
+            Capability::Lock => Capability::CAP_LOCK,
+            Capability::NetSave => Capability::CAP_NET_SAVE,
+            Capability::Hbo => Capability::CAP_HBO,
+            Capability::PowerSave => Capability::CAP_POWER_SAVE,
+            Capability::Counters => Capability::CAP_COUNTERS,
+            Capability::JamDetect => Capability::CAP_JAM_DETECT,
+            Capability::PeekPoke => Capability::CAP_PEEK_POKE,
+            Capability::WritableRawStream => Capability::CAP_WRITABLE_RAW_STREAM,
+            Capability::Gpio => Capability::CAP_GPIO,
+            Capability::Trng => Capability::CAP_TRNG,
+            Capability::CmdMulti => Capability::CAP_CMD_MULTI,
+            Capability::UnsolUpdateFilter => Capability::CAP_UNSOL_UPDATE_FILTER,
+            Capability::McuPowerState => Capability::CAP_MCU_POWER_STATE,
+            Capability::Pcap => Capability::CAP_PCAP,
+            Capability::Ieee802154_2003 => Capability::CAP_802_15_4_2003,
+            Capability::Ieee802154_2006 => Capability::CAP_802_15_4_2006,
+            Capability::Ieee802154_2450MhzOqpsk => Capability::CAP_802_15_4_2450MHZ_OQPSK,
+            Capability::Ieee802154_915MhzOqpsk => Capability::CAP_802_15_4_915MHZ_OQPSK,
+            Capability::Ieee802154_868MhzOqpsk => Capability::CAP_802_15_4_868MHZ_OQPSK,
+            Capability::ConfigFtd => Capability::CAP_CONFIG_FTD,
+            Capability::ConfigMtd => Capability::CAP_CONFIG_MTD,
+            Capability::ConfigRadio => Capability::CAP_CONFIG_RADIO,
+            Capability::RoleRouter => Capability::CAP_ROLE_ROUTER,
+            Capability::RoleSleepy => Capability::CAP_ROLE_SLEEPY,
+            Capability::NetThread1_0 => Capability::CAP_NET_THREAD_1_0,
+            Capability::NetThread1_1 => Capability::CAP_NET_THREAD_1_1,
+            Capability::NetThread1_2 => Capability::CAP_NET_THREAD_1_2,
+            Capability::MacAllowlist => Capability::CAP_MAC_ALLOWLIST,
+            Capability::MacRaw => Capability::CAP_MAC_RAW,
+            Capability::Unknown(id) => id,
+        }
+    }
+}
+
+/// Decode the packed list of capabilities from a [`Property::Caps`](crate::Property::Caps) payload.
+pub fn decode_capability_list(bytes: &[u8]) -> CapabilityList<'_> {
+    CapabilityList { bytes }
+}
+
+/// Iterator over the capabilities in a [`Property::Caps`](crate::Property::Caps) payload, created by
+/// [`decode_capability_list`].
+///
+/// Yields an error and stops if the last packed integer is truncated.
+#[derive(Clone, Debug)]
+pub struct CapabilityList<'a> {
+    bytes: &'a [u8],
+}
+
+impl Iterator for CapabilityList<'_> {
+    type Item = Result<Capability, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let (id, count) = PackedU32::decode(self.bytes);
+        if count == 0 {
+            let len = self.bytes.len();
+            self.bytes = &[];
+            return Some(Err(Error::PacketLength(len)));
+        }
+
+        self.bytes = &self.bytes[count..];
+        Some(Ok(Capability::from(id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capability_id_round_trip() {
+        for id in 0..1024 {
+            assert_eq!(u32::from(Capability::from(id)), id);
+        }
+    }
+
+    #[test]
+    fn decode_caps_list() {
+        let bytes = [0x05, 0x12, 0x20, 0x34, 0x81, 0x04, 0xFF, 0x01];
+        let caps: Vec<_> = decode_capability_list(&bytes)
+            .map(|cap| cap.unwrap())
+            .collect();
+
+        assert_eq!(
+            caps,
+            vec![
+                Capability::Counters,
+                Capability::Ieee802154_2450MhzOqpsk,
+                Capability::ConfigFtd,
+                Capability::NetThread1_0,
+                Capability::MacRaw,
+                Capability::Unknown(255),
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_caps_list_truncated() {
+        let mut caps = decode_capability_list(&[0x05, 0x81]);
+        assert_eq!(caps.next(), Some(Ok(Capability::Counters)));
+        assert_eq!(caps.next(), Some(Err(Error::PacketLength(1))));
+        assert_eq!(caps.next(), None);
+    }
+}
@@ -0,0 +1,99 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+
+/// A set of 802.15.4 channels, such as the ones reported by
+/// [`Property::PhyChanSupported`](crate::Property::PhyChanSupported).
+///
+/// On the wire this is a list of `uint8` channel numbers which is stored as a [`u32`] bitmask, bit `n` being set
+/// when channel `n` is present.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ChannelMask(pub u32);
+
+impl ChannelMask {
+    /// The highest channel number that fits in the mask.
+    pub const MAX_CHANNEL: u8 = 31;
+
+    /// The raw bitmask of channels.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Check if the channel is in the mask.
+    pub fn contains(&self, channel: u8) -> bool {
+        channel <= Self::MAX_CHANNEL && self.0 & (1 << channel) != 0
+    }
+
+    /// Iterate over the channels in the mask in ascending order.
+    pub fn channels(&self) -> impl Iterator<Item = u8> {
+        let mask = *self;
+        (0..=Self::MAX_CHANNEL).filter(move |channel| mask.contains(*channel))
+    }
+}
+
+impl From<u32> for ChannelMask {
+    fn from(bits: u32) -> Self {
+        ChannelMask(bits)
+    }
+}
+
+impl From<ChannelMask> for u32 {
+    fn from(mask: ChannelMask) -> Self {
+        mask.0
+    }
+}
+
+impl<'a> Decode<'a> for ChannelMask {
+    /// Decode a list of channels, consuming the rest of the slice.
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let mut mask = 0;
+
+        for &channel in bytes {
+            if channel > Self::MAX_CHANNEL {
+                return Err(Error::Channel(channel));
+            }
+            mask |= 1 << channel;
+        }
+
+        Ok((ChannelMask(mask), bytes.len()))
+    }
+}
+
+impl Encode for ChannelMask {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        let mut count = 0;
+
+        for channel in self.channels() {
+            buffer.put_u8(channel);
+            count += 1;
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_channels() {
+        let (mask, count) = ChannelMask::decode(&[11, 15, 26]).unwrap();
+        assert_eq!(mask.bits(), 0x0400_8800);
+        assert_eq!(count, 3);
+
+        assert!(mask.contains(15));
+        assert!(!mask.contains(12));
+        assert!(!mask.contains(40));
+        assert_eq!(mask.channels().collect::<Vec<_>>(), vec![11, 15, 26]);
+
+        let mut buffer = BytesMut::new();
+        assert_eq!(mask.encode(&mut buffer), 3);
+        assert_eq!(&buffer[..], &[11, 15, 26]);
+    }
+
+    #[test]
+    fn decode_invalid_channel() {
+        assert_eq!(ChannelMask::decode(&[11, 32]), Err(Error::Channel(32)));
+    }
+}
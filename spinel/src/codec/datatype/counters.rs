@@ -0,0 +1,111 @@
+use super::Decode;
+use crate::{Error, Property};
+
+/// A snapshot of the MAC layer counters of a device.
+///
+/// Each counter is a separate property. Request each of [`MacCounters::PROPERTIES`] and pass the values to
+/// [`MacCounters::update`] to fill in the struct.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MacCounters {
+    /// The total number of transmitted packets.
+    pub tx_total: u32,
+
+    /// The number of transmitted packets that were acknowledged.
+    pub tx_acked: u32,
+
+    /// The number of retransmission attempts.
+    pub tx_retry: u32,
+
+    /// The number of transmissions that failed CCA.
+    pub tx_err_cca: u32,
+
+    /// The number of transmissions that were aborted.
+    pub tx_err_abort: u32,
+
+    /// The total number of received packets.
+    pub rx_total: u32,
+
+    /// The number of received packets with a bad FCS.
+    pub rx_err_bad_fcs: u32,
+
+    /// The number of received packets dropped for other reasons.
+    pub rx_err_other: u32,
+
+    /// The number of duplicate packets received.
+    pub rx_dup: u32,
+}
+
+impl MacCounters {
+    /// The counter properties that make up [`MacCounters`].
+    pub const PROPERTIES: [Property; 9] = [
+        Property::CntrTxPktTotal,
+        Property::CntrTxPktAcked,
+        Property::CntrTxPktRetry,
+        Property::CntrTxErrCca,
+        Property::CntrTxErrAbort,
+        Property::CntrRxPktTotal,
+        Property::CntrRxErrBadFcs,
+        Property::CntrRxErrOther,
+        Property::CntrRxPktDup,
+    ];
+
+    /// Update a counter from the value of its property.
+    ///
+    /// Returns [`Error::Property`] if the property is not one of [`MacCounters::PROPERTIES`].
+    pub fn update(&mut self, prop: &Property, value: &[u8]) -> Result<(), Error> {
+        let counter = match prop {
+            Property::CntrTxPktTotal => &mut self.tx_total,
+            Property::CntrTxPktAcked => &mut self.tx_acked,
+            Property::CntrTxPktRetry => &mut self.tx_retry,
+            Property::CntrTxErrCca => &mut self.tx_err_cca,
+            Property::CntrTxErrAbort => &mut self.tx_err_abort,
+            Property::CntrRxPktTotal => &mut self.rx_total,
+            Property::CntrRxErrBadFcs => &mut self.rx_err_bad_fcs,
+            Property::CntrRxErrOther => &mut self.rx_err_other,
+            Property::CntrRxPktDup => &mut self.rx_dup,
+            _ => return Err(Error::Property(prop.id())),
+        };
+
+        *counter = u32::decode(value)?.0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_counters() {
+        let mut counters = MacCounters::default();
+
+        counters
+            .update(&Property::CntrTxPktTotal, &[0x10, 0x27, 0x00, 0x00])
+            .unwrap();
+        counters
+            .update(&Property::CntrTxErrCca, &[0x05, 0x00, 0x00, 0x00])
+            .unwrap();
+
+        assert_eq!(
+            counters,
+            MacCounters {
+                tx_total: 10_000,
+                tx_err_cca: 5,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn update_rejects_other_properties() {
+        let mut counters = MacCounters::default();
+        assert_eq!(
+            counters.update(&Property::CntrReset, &[0x01]),
+            Err(Error::Property(0x500))
+        );
+        assert_eq!(
+            counters.update(&Property::CntrRxPktTotal, &[0x01]),
+            Err(Error::PacketLength(1))
+        );
+    }
+}
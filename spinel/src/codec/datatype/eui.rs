@@ -0,0 +1,114 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+use core::fmt;
+
+macro_rules! impl_eui {
+    ($name:ident, $len:expr) => {
+        impl $name {
+            /// Length of the address in bytes.
+            pub const LEN: usize = $len;
+
+            /// Get the address bytes in wire order.
+            pub fn as_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl<'a> Decode<'a> for $name {
+            fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+                let value = bytes
+                    .get(..Self::LEN)
+                    .ok_or(Error::PacketLength(bytes.len()))?;
+
+                let mut array = [0; $len];
+                array.copy_from_slice(value);
+                Ok((Self(array), Self::LEN))
+            }
+        }
+
+        impl Encode for $name {
+            fn encode(&self, buffer: &mut BytesMut) -> usize {
+                buffer.put_slice(&self.0);
+                Self::LEN
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (i, byte) in self.0.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ":")?;
+                    }
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// An IEEE EUI-64 address (Spinel datatype `E`), such as an 802.15.4 extended address.
+///
+/// The bytes are stored in the same big-endian order they are sent on the wire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Eui64(pub [u8; 8]);
+
+/// An IEEE EUI-48 address (Spinel datatype `e`).
+///
+/// The bytes are stored in the same big-endian order they are sent on the wire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Eui48(pub [u8; 6]);
+
+impl_eui!(Eui64, 8);
+impl_eui!(Eui48, 6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Property};
+    use bytes::Bytes;
+
+    // PropertyValueIs(HardwareAddress) response
+    const TEST_RESP_HWADDR: [u8; 10] = [0x06, 0x08, 0x18, 0xb4, 0x30, 0x00, 0x00, 0x3d, 0x2e, 0x4f];
+
+    #[test]
+    fn decode_eui64_from_property_value() {
+        let cmd = Command::decode(&Bytes::from_static(&TEST_RESP_HWADDR)).unwrap();
+        let Command::PropertyValueIs(Property::HardwareAddress, value) = cmd else {
+            panic!("unexpected command: {cmd:?}");
+        };
+
+        let (eui, count) = Eui64::decode(&value).unwrap();
+        assert_eq!(count, Eui64::LEN);
+        assert_eq!(eui, Eui64([0x18, 0xb4, 0x30, 0x00, 0x00, 0x3d, 0x2e, 0x4f]));
+
+        let mut buffer = BytesMut::new();
+        eui.encode(&mut buffer);
+        assert_eq!(buffer.freeze(), value);
+    }
+
+    #[test]
+    fn decode_eui_fails_on_short_buffer() {
+        assert_eq!(Eui64::decode(&[0x00; 7]), Err(Error::PacketLength(7)));
+        assert_eq!(Eui48::decode(&[0x00; 5]), Err(Error::PacketLength(5)));
+    }
+
+    #[test]
+    fn display_eui64() {
+        let eui = Eui64([0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]);
+        assert_eq!(eui.to_string(), "00:11:22:33:44:55:66:77");
+    }
+
+    #[test]
+    fn display_eui48() {
+        let eui = Eui48([0xde, 0xad, 0xbe, 0xef, 0x00, 0x01]);
+        assert_eq!(eui.to_string(), "de:ad:be:ef:00:01");
+    }
+}
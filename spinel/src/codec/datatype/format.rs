@@ -0,0 +1,461 @@
+//! Pack and unpack Spinel datatypes described by a format string.
+//!
+//! The format strings follow the notation used by OpenThread's `spinel_datatype_pack` and
+//! `spinel_datatype_unpack`:
+//!
+//! | Format   | Value                  |
+//! |----------|------------------------|
+//! | `b`      | [`Value::Bool`]        |
+//! | `C`      | [`Value::U8`]          |
+//! | `c`      | [`Value::I8`]          |
+//! | `S`      | [`Value::U16`]         |
+//! | `s`      | [`Value::I16`]         |
+//! | `L`      | [`Value::U32`]         |
+//! | `l`      | [`Value::I32`]         |
+//! | `i`      | [`Value::PackedU32`]   |
+//! | `6`      | [`Value::Ipv6`]        |
+//! | `E`      | [`Value::Eui64`]       |
+//! | `e`      | [`Value::Eui48`]       |
+//! | `U`      | [`Value::Utf8`]        |
+//! | `d`      | [`Value::Data`]        |
+//! | `D`      | [`Value::Data`]        |
+//! | `t(...)` | [`Value::Struct`]      |
+//! | `A(...)` | [`Value::Array`]       |
+//!
+//! `d` is prefixed with its length as a `uint16`, while `D` has no prefix and runs to the end of the enclosing
+//! struct or payload. Structs are prefixed with their length as a `uint16`. Arrays have no prefix and repeat the
+//! inner datatype until the end of the enclosing struct or payload, so the inner format of an array must describe
+//! a single datatype.
+use super::{Decode, Encode, Eui48, Eui64, Ipv6Address, PackedU32};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+
+/// A value that can be packed into, or unpacked from, a Spinel payload.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    Bool(bool),
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    PackedU32(u32),
+    Ipv6(Ipv6Address),
+    Eui64(Eui64),
+    Eui48(Eui48),
+    Utf8(&'a str),
+    Data(&'a [u8]),
+    Struct(Vec<Value<'a>>),
+    Array(Vec<Value<'a>>),
+}
+
+/// A single datatype parsed from the front of a format string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Item<'f> {
+    Scalar(u8),
+    Struct(&'f str),
+    Array(&'f str),
+}
+
+/// Largest value that fits in the three bytes Spinel allows for a packed integer.
+const PACKED_U32_MAX: u32 = (1 << 21) - 1;
+
+/// Length of the `uint16` prefix of structs and data blobs.
+const LENGTH_PREFIX_LEN: usize = 2;
+
+/// Split the next datatype off the front of the format string.
+fn next_item<'f>(format: &mut &'f str) -> Result<Option<Item<'f>>, Error> {
+    let bytes = format.as_bytes();
+
+    let Some(&symbol) = bytes.first() else {
+        return Ok(None);
+    };
+
+    // Every symbol is a single ASCII byte, anything else would also split a multi-byte character below
+    if !symbol.is_ascii() {
+        return Err(Error::PackFormat);
+    }
+
+    if symbol != b't' && symbol != b'A' {
+        *format = &format[1..];
+        return Ok(Some(Item::Scalar(symbol)));
+    }
+
+    if bytes.get(1) != Some(&b'(') {
+        return Err(Error::PackFormat);
+    }
+
+    let mut depth = 0;
+    for (i, &byte) in bytes.iter().enumerate().skip(1) {
+        match byte {
+            b'(' => depth += 1,
+            b')' => depth -= 1,
+            _ => continue,
+        }
+
+        if depth == 0 {
+            let inner = &format[2..i];
+            *format = &format[i + 1..];
+
+            return Ok(Some(match symbol {
+                b't' => Item::Struct(inner),
+                _ => Item::Array(inner),
+            }));
+        }
+    }
+
+    Err(Error::PackFormat)
+}
+
+/// Pack the values according to the format string and write them to the buffer.
+///
+/// Returns the number of bytes that were written, or [`Error::PackFormat`] if the values do not match the format.
+pub fn pack(format: &str, values: &[Value<'_>], buffer: &mut BytesMut) -> Result<usize, Error> {
+    let mut format = format;
+    let mut values = values.iter();
+    let mut count = 0;
+
+    while let Some(item) = next_item(&mut format)? {
+        let value = values.next().ok_or(Error::PackFormat)?;
+        count += pack_item(item, value, buffer)?;
+    }
+
+    if values.next().is_some() {
+        return Err(Error::PackFormat);
+    }
+
+    Ok(count)
+}
+
+fn pack_item(item: Item<'_>, value: &Value<'_>, buffer: &mut BytesMut) -> Result<usize, Error> {
+    let count = match (item, value) {
+        (Item::Scalar(b'b'), Value::Bool(value)) => value.encode(buffer),
+        (Item::Scalar(b'C'), Value::U8(value)) => value.encode(buffer),
+        (Item::Scalar(b'c'), Value::I8(value)) => value.encode(buffer),
+        (Item::Scalar(b'S'), Value::U16(value)) => value.encode(buffer),
+        (Item::Scalar(b's'), Value::I16(value)) => value.encode(buffer),
+        (Item::Scalar(b'L'), Value::U32(value)) => value.encode(buffer),
+        (Item::Scalar(b'l'), Value::I32(value)) => value.encode(buffer),
+        (Item::Scalar(b'i'), Value::PackedU32(value)) => {
+            if *value > PACKED_U32_MAX {
+                return Err(Error::PackedU32ByteCount);
+            }
+            PackedU32::write_to_buffer(*value, buffer)
+        }
+        (Item::Scalar(b'6'), Value::Ipv6(value)) => value.encode(buffer),
+        (Item::Scalar(b'E'), Value::Eui64(value)) => value.encode(buffer),
+        (Item::Scalar(b'e'), Value::Eui48(value)) => value.encode(buffer),
+        (Item::Scalar(b'U'), Value::Utf8(value)) => value.encode(buffer),
+        (Item::Scalar(b'd'), Value::Data(value)) => {
+            if value.len() > u16::MAX as usize {
+                return Err(Error::PacketLength(value.len()));
+            }
+            value.encode(buffer)
+        }
+        (Item::Scalar(b'D'), Value::Data(value)) => {
+            buffer.put_slice(value);
+            value.len()
+        }
+        (Item::Struct(inner), Value::Struct(values)) => {
+            let start = buffer.len();
+            buffer.put_u16_le(0);
+
+            let len = pack(inner, values, buffer)?;
+            let len = u16::try_from(len).map_err(|_| Error::PacketLength(len))?;
+            buffer[start..start + LENGTH_PREFIX_LEN].copy_from_slice(&len.to_le_bytes());
+
+            LENGTH_PREFIX_LEN + usize::from(len)
+        }
+        (Item::Array(inner), Value::Array(values)) => {
+            let item = single_item(inner)?;
+
+            let mut count = 0;
+            for value in values {
+                count += pack_item(item, value, buffer)?;
+            }
+            count
+        }
+        _ => return Err(Error::PackFormat),
+    };
+
+    Ok(count)
+}
+
+/// Unpack values from the payload according to the format string.
+///
+/// Any bytes following the last datatype in the format are ignored, which allows newer devices to append fields
+/// to a payload.
+pub fn unpack<'a>(format: &str, bytes: &'a [u8]) -> Result<Vec<Value<'a>>, Error> {
+    let mut format = format;
+    let mut offset = 0;
+    let mut values = Vec::new();
+
+    while let Some(item) = next_item(&mut format)? {
+        let (value, count) = unpack_item(item, &bytes[offset..])?;
+        values.push(value);
+        offset += count;
+    }
+
+    Ok(values)
+}
+
+fn unpack_item<'a>(item: Item<'_>, bytes: &'a [u8]) -> Result<(Value<'a>, usize), Error> {
+    fn decode<'a, T: Decode<'a>>(
+        bytes: &'a [u8],
+        f: impl FnOnce(T) -> Value<'a>,
+    ) -> Result<(Value<'a>, usize), Error> {
+        T::decode(bytes).map(|(value, count)| (f(value), count))
+    }
+
+    match item {
+        Item::Scalar(b'b') => decode(bytes, Value::Bool),
+        Item::Scalar(b'C') => decode(bytes, Value::U8),
+        Item::Scalar(b'c') => decode(bytes, Value::I8),
+        Item::Scalar(b'S') => decode(bytes, Value::U16),
+        Item::Scalar(b's') => decode(bytes, Value::I16),
+        Item::Scalar(b'L') => decode(bytes, Value::U32),
+        Item::Scalar(b'l') => decode(bytes, Value::I32),
+        Item::Scalar(b'i') => {
+            if bytes.is_empty() {
+                return Err(Error::PacketLength(0));
+            }
+
+            let (value, count) = PackedU32::decode_unbounded(bytes)?;
+            if value > PACKED_U32_MAX {
+                return Err(Error::PackedU32ByteCount);
+            }
+            Ok((Value::PackedU32(value), count))
+        }
+        Item::Scalar(b'6') => decode(bytes, Value::Ipv6),
+        Item::Scalar(b'E') => decode(bytes, Value::Eui64),
+        Item::Scalar(b'e') => decode(bytes, Value::Eui48),
+        Item::Scalar(b'U') => decode(bytes, Value::Utf8),
+        Item::Scalar(b'd') => decode(bytes, Value::Data),
+        Item::Scalar(b'D') => Ok((Value::Data(bytes), bytes.len())),
+        Item::Scalar(_) => Err(Error::PackFormat),
+        Item::Struct(inner) => {
+            let (contents, count) = <&[u8]>::decode(bytes)?;
+            Ok((Value::Struct(unpack(inner, contents)?), count))
+        }
+        Item::Array(inner) => {
+            let item = single_item(inner)?;
+
+            let mut offset = 0;
+            let mut values = Vec::new();
+            while offset < bytes.len() {
+                let (value, count) = unpack_item(item, &bytes[offset..])?;
+                values.push(value);
+                offset += count;
+            }
+
+            Ok((Value::Array(values), offset))
+        }
+    }
+}
+
+/// Parse an array's inner format, which must describe exactly one datatype.
+fn single_item(format: &str) -> Result<Item<'_>, Error> {
+    let mut format = format;
+
+    match next_item(&mut format)? {
+        Some(item) if format.is_empty() => Ok(item),
+        _ => Err(Error::PackFormat),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(format: &str, values: &[Value<'_>], wire: &[u8]) {
+        let mut buffer = BytesMut::new();
+        assert_eq!(pack(format, values, &mut buffer), Ok(wire.len()));
+        assert_eq!(&buffer[..], wire);
+        assert_eq!(unpack(format, wire).as_deref(), Ok(values));
+    }
+
+    #[test]
+    fn scalar_round_trip() {
+        round_trip(
+            "bCcSsLli",
+            &[
+                Value::Bool(true),
+                Value::U8(0xA5),
+                Value::I8(-2),
+                Value::U16(0x1234),
+                Value::I16(-2),
+                Value::U32(0x1234_5678),
+                Value::I32(-2),
+                Value::PackedU32(1337),
+            ],
+            &[
+                0x01, 0xA5, 0xFE, 0x34, 0x12, 0xFE, 0xFF, 0x78, 0x56, 0x34, 0x12, 0xFE, 0xFF, 0xFF,
+                0xFF, 0xB9, 0x0A,
+            ],
+        );
+    }
+
+    #[test]
+    fn prop_value_set_header() {
+        // "Cii" is how OpenThread builds the header of a PROP_VALUE_SET for PROP_CNTR_RESET
+        round_trip(
+            "Cii",
+            &[
+                Value::U8(0x81),
+                Value::PackedU32(0x03),
+                Value::PackedU32(0x500),
+            ],
+            &[0x81, 0x03, 0x80, 0x0A],
+        );
+    }
+
+    #[test]
+    fn address_and_string_round_trip() {
+        let eui64 = Eui64([0x18, 0xB4, 0x30, 0x00, 0x00, 0x3D, 0x2E, 0x4F]);
+        let eui48 = Eui48([0x02, 0x00, 0x5E, 0x10, 0x00, 0x01]);
+        let ipv6 = Ipv6Address::new(0xFE80, 0, 0, 0, 0, 0, 0, 1);
+
+        let mut wire = Vec::new();
+        wire.extend_from_slice(eui64.as_bytes());
+        wire.extend_from_slice(eui48.as_bytes());
+        wire.extend_from_slice(b"OpenThread\0");
+        wire.extend_from_slice(&ipv6.octets());
+
+        round_trip(
+            "EeU6",
+            &[
+                Value::Eui64(eui64),
+                Value::Eui48(eui48),
+                Value::Utf8("OpenThread"),
+                Value::Ipv6(ipv6),
+            ],
+            &wire,
+        );
+    }
+
+    #[test]
+    fn data_round_trip() {
+        round_trip(
+            "dD",
+            &[Value::Data(&[0xDE, 0xAD]), Value::Data(&[0xBE, 0xEF, 0x00])],
+            &[0x02, 0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x00],
+        );
+    }
+
+    #[test]
+    fn struct_round_trip() {
+        // An entry of PROP_IPV6_ADDRESS_TABLE: t(6CLL)
+        let address = Ipv6Address::new(0xFD00, 0, 0, 0, 0, 0, 0, 1);
+        let mut wire = vec![0x19, 0x00];
+        wire.extend_from_slice(&address.octets());
+        wire.extend_from_slice(&[0x40, 0xFF, 0xFF, 0xFF, 0xFF, 0x10, 0x0E, 0x00, 0x00]);
+
+        round_trip(
+            "t(6CLL)",
+            &[Value::Struct(vec![
+                Value::Ipv6(address),
+                Value::U8(64),
+                Value::U32(u32::MAX),
+                Value::U32(3600),
+            ])],
+            &wire,
+        );
+    }
+
+    #[test]
+    fn array_round_trip() {
+        // PROP_PHY_CHAN_SUPPORTED: A(C)
+        round_trip(
+            "A(C)",
+            &[Value::Array(vec![
+                Value::U8(11),
+                Value::U8(15),
+                Value::U8(26),
+            ])],
+            &[0x0B, 0x0F, 0x1A],
+        );
+
+        // PROP_CAPS: A(i)
+        round_trip(
+            "A(i)",
+            &[Value::Array(vec![
+                Value::PackedU32(5),
+                Value::PackedU32(512),
+            ])],
+            &[0x05, 0x80, 0x04],
+        );
+    }
+
+    #[test]
+    fn array_of_structs_round_trip() {
+        round_trip(
+            "SA(t(CS))",
+            &[
+                Value::U16(1),
+                Value::Array(vec![
+                    Value::Struct(vec![Value::U8(1), Value::U16(0x0203)]),
+                    Value::Struct(vec![Value::U8(4), Value::U16(0x0506)]),
+                ]),
+            ],
+            &[
+                0x01, 0x00, 0x03, 0x00, 0x01, 0x03, 0x02, 0x03, 0x00, 0x04, 0x06, 0x05,
+            ],
+        );
+    }
+
+    #[test]
+    fn unpack_ignores_trailing_struct_fields() {
+        let values = unpack("t(C)S", &[0x02, 0x00, 0x01, 0xFF, 0x34, 0x12]).unwrap();
+        assert_eq!(
+            values,
+            vec![Value::Struct(vec![Value::U8(1)]), Value::U16(0x1234)]
+        );
+    }
+
+    #[test]
+    fn pack_rejects_mismatched_values() {
+        let mut buffer = BytesMut::new();
+        assert_eq!(
+            pack("C", &[Value::U16(1)], &mut buffer),
+            Err(Error::PackFormat)
+        );
+        assert_eq!(
+            pack("CC", &[Value::U8(1)], &mut buffer),
+            Err(Error::PackFormat)
+        );
+        assert_eq!(
+            pack("C", &[Value::U8(1), Value::U8(2)], &mut buffer),
+            Err(Error::PackFormat)
+        );
+        assert_eq!(
+            pack("i", &[Value::PackedU32(1 << 21)], &mut buffer),
+            Err(Error::PackedU32ByteCount)
+        );
+    }
+
+    #[test]
+    fn invalid_format_strings() {
+        assert_eq!(unpack("Z", &[0x00]), Err(Error::PackFormat));
+        assert_eq!(unpack("t(C", &[0x01, 0x00, 0x00]), Err(Error::PackFormat));
+        assert_eq!(unpack("tC", &[0x01, 0x00, 0x00]), Err(Error::PackFormat));
+        assert_eq!(unpack("A(CS)", &[0x00]), Err(Error::PackFormat));
+    }
+
+    #[test]
+    fn non_ascii_format_strings() {
+        let mut buffer = BytesMut::new();
+        assert_eq!(pack("é", &[], &mut buffer), Err(Error::PackFormat));
+        assert_eq!(unpack("Cé", &[0x01]), Err(Error::PackFormat));
+        assert_eq!(unpack("t(é)", &[0x00, 0x00]), Err(Error::PackFormat));
+    }
+
+    #[test]
+    fn unpack_fails_on_short_payload() {
+        assert_eq!(unpack("L", &[0x01, 0x02]), Err(Error::PacketLength(2)));
+        assert_eq!(
+            unpack("t(C)", &[0x02, 0x00, 0x01]),
+            Err(Error::PacketLength(3))
+        );
+    }
+}
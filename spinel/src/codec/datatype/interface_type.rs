@@ -0,0 +1,128 @@
+use super::{Decode, Encode, PackedU32};
+use crate::Error;
+use bytes::BytesMut;
+use core::fmt;
+
+/// The network protocol of the device, as reported by [`Property::InterfaceType`](crate::Property::InterfaceType).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InterfaceType {
+    /// The device is running a bootloader.
+    Bootloader,
+
+    /// The device implements the ZigBee IP protocol.
+    Zigbee,
+
+    /// The device implements the Thread protocol.
+    Thread,
+
+    /// An interface type this crate does not recognize.
+    Unknown(u32),
+}
+
+impl InterfaceType {
+    const PROTOCOL_TYPE_BOOTLOADER: u32 = 0;
+    const PROTOCOL_TYPE_ZIGBEE_IP: u32 = 2;
+    const PROTOCOL_TYPE_THREAD: u32 = 3;
+
+    /// Check that the device implements the Thread protocol.
+    ///
+    /// Returns [`Error::WrongInterface`] otherwise, so Thread specific requests are not sent to a device that
+    /// would only answer them with [`Status::PropertyNotFound`](crate::Status::PropertyNotFound).
+    pub fn ensure_thread(&self) -> Result<(), Error> {
+        match self {
+            InterfaceType::Thread => Ok(()),
+            other => Err(Error::WrongInterface(*other)),
+        }
+    }
+}
+
+impl fmt::Display for InterfaceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterfaceType::Bootloader => write!(f, "Bootloader"),
+            InterfaceType::Zigbee => write!(f, "Zigbee"),
+            InterfaceType::Thread => write!(f, "Thread"),
+            InterfaceType::Unknown(id) => write!(f, "Unknown({})", id),
+        }
+    }
+}
+
+impl From<u32> for InterfaceType {
+    fn from(value: u32) -> Self {
+        match value {
+            Self::PROTOCOL_TYPE_BOOTLOADER => Self::Bootloader,
+            Self::PROTOCOL_TYPE_ZIGBEE_IP => Self::Zigbee,
+            Self::PROTOCOL_TYPE_THREAD => Self::Thread,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<InterfaceType> for u32 {
+    fn from(value: InterfaceType) -> u32 {
+        match value {
+            InterfaceType::Bootloader => InterfaceType::PROTOCOL_TYPE_BOOTLOADER,
+            InterfaceType::Zigbee => InterfaceType::PROTOCOL_TYPE_ZIGBEE_IP,
+            InterfaceType::Thread => InterfaceType::PROTOCOL_TYPE_THREAD,
+            InterfaceType::Unknown(id) => id,
+        }
+    }
+}
+
+impl<'a> Decode<'a> for InterfaceType {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = PackedU32::decode(bytes);
+        if count == 0 {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
+        Ok((Self::from(value), count))
+    }
+}
+
+impl Encode for InterfaceType {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        PackedU32::write_to_buffer(u32::from(*self), buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_thread() {
+        assert_eq!(
+            InterfaceType::decode(&[0x03]),
+            Ok((InterfaceType::Thread, 1))
+        );
+    }
+
+    #[test]
+    fn decode_unknown() {
+        assert_eq!(
+            InterfaceType::decode(&[0x80, 0x01]),
+            Ok((InterfaceType::Unknown(128), 2))
+        );
+    }
+
+    #[test]
+    fn decode_empty() {
+        assert_eq!(InterfaceType::decode(&[]), Err(Error::PacketLength(0)));
+    }
+
+    #[test]
+    fn ensure_thread() {
+        assert_eq!(InterfaceType::Thread.ensure_thread(), Ok(()));
+        assert_eq!(
+            InterfaceType::Zigbee.ensure_thread(),
+            Err(Error::WrongInterface(InterfaceType::Zigbee))
+        );
+    }
+
+    #[test]
+    fn from_u32() {
+        assert_eq!(InterfaceType::from(2), InterfaceType::Zigbee);
+        assert_eq!(u32::from(InterfaceType::Thread), 3);
+    }
+}
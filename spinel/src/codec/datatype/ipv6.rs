@@ -0,0 +1,150 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "std")] {
+        /// An IPv6 address (Spinel datatype `6`).
+        pub type Ipv6Address = std::net::Ipv6Addr;
+
+        fn from_octets(octets: [u8; 16]) -> Ipv6Address {
+            Ipv6Address::from(octets)
+        }
+
+        fn to_octets(address: &Ipv6Address) -> [u8; 16] {
+            address.octets()
+        }
+    } else {
+        /// An IPv6 address (Spinel datatype `6`).
+        pub type Ipv6Address = [u8; 16];
+
+        fn from_octets(octets: [u8; 16]) -> Ipv6Address {
+            octets
+        }
+
+        fn to_octets(address: &Ipv6Address) -> [u8; 16] {
+            *address
+        }
+    }
+}
+
+/// Length of an IPv6 address in bytes.
+const IPV6_ADDRESS_LEN: usize = 16;
+
+impl<'a> Decode<'a> for Ipv6Address {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let value = bytes
+            .get(..IPV6_ADDRESS_LEN)
+            .ok_or(Error::PacketLength(bytes.len()))?;
+
+        let mut octets = [0; IPV6_ADDRESS_LEN];
+        octets.copy_from_slice(value);
+        Ok((from_octets(octets), IPV6_ADDRESS_LEN))
+    }
+}
+
+impl Encode for Ipv6Address {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        buffer.put_slice(&to_octets(self));
+        IPV6_ADDRESS_LEN
+    }
+}
+
+/// Walk a payload of length-delimited structs that each begin with an IPv6 address.
+///
+/// This is the layout used by address table properties such as `IPV6_ADDRESS_TABLE`, where each entry is a
+/// `uint16` length followed by a struct whose first field is the address. Any trailing struct fields are skipped.
+pub fn decode_ipv6_list(bytes: &[u8]) -> Ipv6AddressList<'_> {
+    Ipv6AddressList { bytes }
+}
+
+/// Iterator over the addresses in a list payload, created by [`decode_ipv6_list`].
+///
+/// Yields an error and stops if an entry is truncated.
+#[derive(Clone, Debug)]
+pub struct Ipv6AddressList<'a> {
+    bytes: &'a [u8],
+}
+
+impl Iterator for Ipv6AddressList<'_> {
+    type Item = Result<Ipv6Address, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let result = u16::decode(self.bytes).and_then(|(len, count)| {
+            let entry = self
+                .bytes
+                .get(count..count + len as usize)
+                .ok_or(Error::PacketLength(self.bytes.len()))?;
+            let (address, _) = Ipv6Address::decode(entry)?;
+
+            self.bytes = &self.bytes[count + len as usize..];
+            Ok(address)
+        });
+
+        if result.is_err() {
+            self.bytes = &[];
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ADDRESS: [u8; 16] = [
+        0xfd, 0x00, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe, 0x00, 0xfc,
+        0x00,
+    ];
+
+    /// Two entries of `t(6CLLC)`: address, prefix length, valid lifetime, preferred lifetime, flags.
+    const TEST_ADDRESS_TABLE: [u8; 56] = [
+        0x1a, 0x00, 0xfd, 0x00, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xfe,
+        0x00, 0xfc, 0x00, 0x40, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1a, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0xb4, 0x30, 0x00, 0x00, 0x3d, 0x2e,
+        0x4f, 0x40, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00,
+    ];
+
+    #[test]
+    fn ipv6_round_trip() {
+        let (address, count) = Ipv6Address::decode(&TEST_ADDRESS).unwrap();
+        assert_eq!(count, 16);
+        assert_eq!(to_octets(&address), TEST_ADDRESS);
+
+        let mut buffer = BytesMut::new();
+        assert_eq!(address.encode(&mut buffer), 16);
+        assert_eq!(&buffer[..], &TEST_ADDRESS);
+    }
+
+    #[test]
+    fn decode_ipv6_fails_on_short_buffer() {
+        assert_eq!(
+            Ipv6Address::decode(&TEST_ADDRESS[..15]),
+            Err(Error::PacketLength(15))
+        );
+    }
+
+    #[test]
+    fn decode_address_table() {
+        let addresses: Vec<_> = decode_ipv6_list(&TEST_ADDRESS_TABLE)
+            .map(|address| to_octets(&address.unwrap()))
+            .collect();
+
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0], TEST_ADDRESS);
+        assert_eq!(addresses[1][..2], [0xfe, 0x80]);
+    }
+
+    #[test]
+    fn decode_address_table_stops_on_truncated_entry() {
+        let mut list = decode_ipv6_list(&TEST_ADDRESS_TABLE[..40]);
+        assert!(list.next().unwrap().is_ok());
+        assert_eq!(list.next(), Some(Err(Error::PacketLength(12))));
+        assert_eq!(list.next(), None);
+    }
+}
@@ -0,0 +1,99 @@
+use super::Decode;
+use crate::Error;
+use core::marker::PhantomData;
+
+/// Walk a payload of length-delimited structs, decoding each entry as a `T`.
+///
+/// This is the layout used by table properties, an array of `t(...)` where each entry is a `uint16` length
+/// followed by the struct. Each entry is decoded from its own slice, so fields added to the struct by newer
+/// firmware are skipped.
+pub fn decode_struct_list<'a, T: Decode<'a>>(bytes: &'a [u8]) -> StructList<'a, T> {
+    StructList {
+        bytes,
+        _entry: PhantomData,
+    }
+}
+
+/// Iterator over the entries in a list payload, created by [`decode_struct_list`].
+///
+/// Yields an error and stops if an entry is truncated or fails to decode.
+#[derive(Clone, Debug)]
+pub struct StructList<'a, T> {
+    bytes: &'a [u8],
+    _entry: PhantomData<T>,
+}
+
+impl<'a, T: Decode<'a>> Iterator for StructList<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let bytes = self.bytes;
+        let result = <&[u8]>::decode(bytes).and_then(|(entry, count)| {
+            let (value, _) = T::decode(entry)?;
+
+            self.bytes = &bytes[count..];
+            Ok(value)
+        });
+
+        if result.is_err() {
+            self.bytes = &[];
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::EnergyScanResult;
+
+    #[test]
+    fn decode_two_entries() {
+        // Two entries of `t(Cc)`, the second with an extra trailing field
+        let bytes = [0x02, 0x00, 0x0B, 0xB0, 0x03, 0x00, 0x0C, 0xC4, 0xFF];
+        let entries: Vec<_> = decode_struct_list::<EnergyScanResult>(&bytes)
+            .map(|entry| entry.unwrap())
+            .collect();
+
+        assert_eq!(
+            entries,
+            vec![
+                EnergyScanResult {
+                    channel: 11,
+                    rssi: -80,
+                },
+                EnergyScanResult {
+                    channel: 12,
+                    rssi: -60,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_truncated_entry() {
+        let mut entries =
+            decode_struct_list::<EnergyScanResult>(&[0x02, 0x00, 0x0B, 0xB0, 0x02, 0x00, 0x0C]);
+        assert_eq!(
+            entries.next(),
+            Some(Ok(EnergyScanResult {
+                channel: 11,
+                rssi: -80,
+            }))
+        );
+        assert_eq!(entries.next(), Some(Err(Error::PacketLength(3))));
+        assert_eq!(entries.next(), None);
+    }
+
+    #[test]
+    fn decode_short_entry() {
+        let mut entries = decode_struct_list::<EnergyScanResult>(&[0x01, 0x00, 0x0B]);
+        assert_eq!(entries.next(), Some(Err(Error::PacketLength(0))));
+        assert_eq!(entries.next(), None);
+    }
+}
@@ -1,9 +1,59 @@
+mod beacon;
+mod capability;
+mod channel_mask;
+mod counters;
+mod eui;
+#[cfg(feature = "std")]
+mod format;
+mod interface_type;
+mod ipv6;
+mod list;
+mod packed_i32;
 mod packed_u32;
+mod power_state;
+mod primitive;
+mod protocol_version;
+mod scan;
 mod status;
+mod stream;
 
+use crate::Error;
+use bytes::BytesMut;
+
+pub use beacon::BeaconInfo;
+pub use capability::{decode_capability_list, Capability, CapabilityList};
+pub use channel_mask::ChannelMask;
+pub use counters::MacCounters;
+pub use eui::{Eui48, Eui64};
+#[cfg(feature = "std")]
+pub use format::{pack, unpack, Value};
+pub use interface_type::InterfaceType;
+pub use ipv6::{decode_ipv6_list, Ipv6Address, Ipv6AddressList};
+pub use list::{decode_struct_list, StructList};
+pub use packed_i32::PackedI32;
 pub use packed_u32::PackedU32;
-pub use status::Status;
+pub use power_state::{HostPowerState, McuPowerState};
+pub use protocol_version::ProtocolVersion;
+pub use scan::{EnergyScanResult, ScanState};
+pub use status::{ResetReason, Status};
+pub use stream::{NetStreamFrame, StreamMetadata};
 
 /// Type alias for `[u8]`.
 /// Used to help clarify the intent of the type when used with packed types.
 pub type PackedByteSlice = [u8];
+
+/// Decode a Spinel datatype from the front of a byte slice.
+pub trait Decode<'a>: Sized {
+    /// Decode a value from the start of the slice.
+    ///
+    /// Returns the decoded value and number of bytes that were read
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error>;
+}
+
+/// Encode a Spinel datatype into a buffer.
+pub trait Encode {
+    /// Encode the value and write it to the buffer.
+    ///
+    /// Returns the number of bytes that were written
+    fn encode(&self, buffer: &mut BytesMut) -> usize;
+}
@@ -0,0 +1,155 @@
+use super::{PackedByteSlice, PackedU32};
+use crate::Error;
+use bytes::BytesMut;
+
+/// A packed representation of an `i32` value used in the Spinel protocol.
+///
+/// The value is zig-zag encoded so that small negative numbers stay small, then packed using the same EXI
+/// representation as [`PackedU32`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackedI32 {
+    /// The packed, zig-zag encoded value.
+    pub(crate) array: [u8; 3],
+}
+
+impl PackedI32 {
+    /// Zig-zag encode an [`i32`] so it can be packed as an unsigned value
+    #[inline]
+    fn zig_zag(value: i32) -> u32 {
+        ((value << 1) ^ (value >> 31)) as u32
+    }
+
+    /// Reverse the zig-zag encoding of an unsigned value
+    #[inline]
+    fn unzig_zag(value: u32) -> i32 {
+        ((value >> 1) as i32) ^ -((value & 1) as i32)
+    }
+
+    /// Encode an [`i32`] value into a packed representation
+    ///
+    /// Returns the packed value and number of bytes that were used
+    #[inline]
+    pub fn encode(value: i32) -> ([u8; 3], usize) {
+        PackedU32::encode(Self::zig_zag(value))
+    }
+
+    /// Decode a packed [`i32`] value from a byte slice
+    ///
+    /// Returns the decoded value and number of bytes that were read
+    #[inline]
+    pub fn decode(bytes: &PackedByteSlice) -> (i32, usize) {
+        let (value, count) = PackedU32::decode(bytes);
+        (Self::unzig_zag(value), count)
+    }
+
+    /// Get the expected length of the packed [`i32`] value
+    #[inline]
+    pub fn packed_len(value: i32) -> usize {
+        PackedU32::packed_len(Self::zig_zag(value))
+    }
+
+    /// Pack the value and write the inner [`i32`] value to a buffer.
+    #[inline]
+    pub fn write_to_buffer(value: i32, buffer: &mut BytesMut) -> usize {
+        PackedU32::write_to_buffer(Self::zig_zag(value), buffer)
+    }
+}
+
+impl From<PackedI32> for i32 {
+    fn from(value: PackedI32) -> Self {
+        PackedI32::decode(&value.array).0
+    }
+}
+
+impl From<i32> for PackedI32 {
+    fn from(value: i32) -> Self {
+        let (array, _) = Self::encode(value);
+        PackedI32 { array }
+    }
+}
+
+impl TryFrom<&PackedByteSlice> for PackedI32 {
+    type Error = Error;
+
+    fn try_from(bytes: &PackedByteSlice) -> Result<Self, Self::Error> {
+        let PackedU32 { array } = PackedU32::try_from(bytes)?;
+        Ok(PackedI32 { array })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestItem {
+        packed: [u8; 3],
+        unpacked: i32,
+        count: usize,
+    }
+
+    const TEST_PACK_ARRAY: [TestItem; 8] = [
+        TestItem {
+            packed: [0x00, 0x00, 0x00],
+            unpacked: 0,
+            count: 1,
+        },
+        TestItem {
+            packed: [0x01, 0x00, 0x00],
+            unpacked: -1,
+            count: 1,
+        },
+        TestItem {
+            packed: [0x02, 0x00, 0x00],
+            unpacked: 1,
+            count: 1,
+        },
+        TestItem {
+            packed: [0x7F, 0x00, 0x00],
+            unpacked: -64,
+            count: 1,
+        },
+        TestItem {
+            packed: [0x80, 0x01, 0x00],
+            unpacked: 64,
+            count: 2,
+        },
+        TestItem {
+            packed: [0xF1, 0x14, 0x00],
+            unpacked: -1_337,
+            count: 2,
+        },
+        TestItem {
+            packed: [0xF2, 0x14, 0x00],
+            unpacked: 1_337,
+            count: 2,
+        },
+        TestItem {
+            packed: [0xFF, 0xFF, 0x7F],
+            unpacked: -1_048_576,
+            count: 3,
+        },
+    ];
+
+    #[test]
+    fn decode_i32() {
+        for item in TEST_PACK_ARRAY.iter() {
+            let test = PackedI32 { array: item.packed };
+            assert_eq!(i32::from(test), item.unpacked);
+
+            let result = PackedI32::decode(&item.packed);
+            assert_eq!(result.0, item.unpacked);
+            assert_eq!(result.1, item.count);
+        }
+    }
+
+    #[test]
+    fn encode_i32() {
+        for item in TEST_PACK_ARRAY.iter() {
+            let (result, count) = PackedI32::encode(item.unpacked);
+            assert_eq!(result, item.packed);
+            assert_eq!(count, item.count);
+            assert_eq!(PackedI32::packed_len(item.unpacked), item.count);
+        }
+    }
+}
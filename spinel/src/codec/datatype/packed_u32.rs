@@ -15,6 +15,9 @@ pub struct PackedU32 {
 }
 
 impl PackedU32 {
+    /// The maximum number of bytes needed to pack any [`u32`] value.
+    const MAX_UNBOUNDED_LEN: usize = 5;
+
     /// Count the number of bytes used to represent the [`u32`] value
     #[inline]
     pub(crate) fn count_bytes(value: &PackedByteSlice) -> usize {
@@ -91,6 +94,39 @@ impl PackedU32 {
         (value, count)
     }
 
+    /// Decode a packed [`u32`] value of up to 5 bytes from a byte slice
+    ///
+    /// Unlike [`PackedU32::decode`], this handles the full [`u32`] range. Returns the decoded value and number of
+    /// bytes that were read, or an error if the value is unterminated or does not fit in a [`u32`].
+    #[inline]
+    pub fn decode_unbounded(bytes: &PackedByteSlice) -> Result<(u32, usize), Error> {
+        // Most values fit in a single byte
+        if let Some(&byte) = bytes.first() {
+            if byte & 0x80 == 0 {
+                return Ok((byte as u32, 1));
+            }
+        }
+
+        let mut value = 0;
+
+        for (i, byte) in bytes.iter().take(Self::MAX_UNBOUNDED_LEN).enumerate() {
+            let bits = (byte & 0x7F) as u32;
+
+            // The fifth byte only carries the top 4 bits of a u32
+            if i == Self::MAX_UNBOUNDED_LEN - 1 && bits > 0x0F {
+                return Err(Error::PackedU32ByteCount);
+            }
+
+            value |= bits << (7 * i);
+
+            if byte & 0x80 == 0 {
+                return Ok((value, i + 1));
+            }
+        }
+
+        Err(Error::PackedU32ByteCount)
+    }
+
     /// Get the expected length of the packed [`u32`] value
     #[inline]
     pub fn packed_len(value: u32) -> usize {
@@ -111,7 +147,7 @@ impl PackedU32 {
 
     /// Get the length of the packed [`u32`] value
     #[cfg(test)]
-    pub fn len(&self) -> usize {
+    fn len(&self) -> usize {
         Self::count_bytes(&self.array)
     }
 }
@@ -223,7 +259,7 @@ mod tests {
         for item in TEST_PACK_ARRAY.iter() {
             let test = PackedU32 { array: item.packed };
 
-            let result: u32 = test.try_into().unwrap();
+            let result = u32::from(test);
             assert_eq!(result, item.unpacked);
             assert_eq!(test.len(), item.count);
 
@@ -233,6 +269,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_unbounded_u32() {
+        for item in TEST_PACK_ARRAY.iter() {
+            let result = PackedU32::decode_unbounded(&item.packed);
+            assert_eq!(result, Ok((item.unpacked, item.count)));
+        }
+
+        let result = PackedU32::decode_unbounded(&[0x80, 0x80, 0x80, 0x01]);
+        assert_eq!(result, Ok((2_097_152, 4)));
+
+        let result = PackedU32::decode_unbounded(&[0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00]);
+        assert_eq!(result, Ok((u32::MAX, 5)));
+    }
+
+    #[test]
+    fn decode_unbounded_invalid() {
+        let result = PackedU32::decode_unbounded(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]);
+        assert_eq!(result, Err(Error::PackedU32ByteCount));
+
+        let result = PackedU32::decode_unbounded(&[0xFF, 0xFF]);
+        assert_eq!(result, Err(Error::PackedU32ByteCount));
+    }
+
     #[test]
     fn encode_u32() {
         for item in TEST_PACK_ARRAY.iter() {
@@ -0,0 +1,244 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::BytesMut;
+use core::fmt;
+
+/// The power state of the host, as declared to the device with
+/// [`Property::HostPowerState`](crate::Property::HostPowerState).
+///
+/// While the host is in a low power state the device buffers frames and only wakes the host for important
+/// events.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HostPowerState {
+    /// The host is offline and should not be woken by the device.
+    Offline,
+
+    /// The host is in deep sleep and should only be woken for critical events.
+    DeepSleep,
+
+    /// Reserved for future use.
+    Reserved,
+
+    /// The host is in a low power state and should only be woken for events it has subscribed to.
+    LowPower,
+
+    /// The host is online and can receive all frames.
+    Online,
+}
+
+impl HostPowerState {
+    const HOST_POWER_STATE_OFFLINE: u8 = 0;
+    const HOST_POWER_STATE_DEEP_SLEEP: u8 = 1;
+    const HOST_POWER_STATE_RESERVED: u8 = 2;
+    const HOST_POWER_STATE_LOW_POWER: u8 = 3;
+    const HOST_POWER_STATE_ONLINE: u8 = 4;
+}
+
+impl fmt::Display for HostPowerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostPowerState::Offline => write!(f, "Offline"),
+            HostPowerState::DeepSleep => write!(f, "DeepSleep"),
+            HostPowerState::Reserved => write!(f, "Reserved"),
+            HostPowerState::LowPower => write!(f, "LowPower"),
+            HostPowerState::Online => write!(f, "Online"),
+        }
+    }
+}
+
+impl TryFrom<u8> for HostPowerState {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            Self::HOST_POWER_STATE_OFFLINE => Ok(Self::Offline),
+            Self::HOST_POWER_STATE_DEEP_SLEEP => Ok(Self::DeepSleep),
+            Self::HOST_POWER_STATE_RESERVED => Ok(Self::Reserved),
+            Self::HOST_POWER_STATE_LOW_POWER => Ok(Self::LowPower),
+            Self::HOST_POWER_STATE_ONLINE => Ok(Self::Online),
+            _ => Err(Error::UnknownPowerState(value)),
+        }
+    }
+}
+
+impl From<HostPowerState> for u8 {
+    fn from(state: HostPowerState) -> u8 {
+        match state {
+            HostPowerState::Offline => HostPowerState::HOST_POWER_STATE_OFFLINE,
+            HostPowerState::DeepSleep => HostPowerState::HOST_POWER_STATE_DEEP_SLEEP,
+            HostPowerState::Reserved => HostPowerState::HOST_POWER_STATE_RESERVED,
+            HostPowerState::LowPower => HostPowerState::HOST_POWER_STATE_LOW_POWER,
+            HostPowerState::Online => HostPowerState::HOST_POWER_STATE_ONLINE,
+        }
+    }
+}
+
+impl<'a> Decode<'a> for HostPowerState {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = u8::decode(bytes)?;
+        Ok((Self::try_from(value)?, count))
+    }
+}
+
+impl Encode for HostPowerState {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        u8::from(*self).encode(buffer)
+    }
+}
+
+/// The power state of the device's MCU, as reported by [`Property::McuPowerState`](crate::Property::McuPowerState).
+///
+/// This only describes whether the MCU itself may sleep, the radio can remain active while the MCU is in a low
+/// power state.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum McuPowerState {
+    /// The MCU is always on.
+    On,
+
+    /// The MCU may enter a low power state when idle.
+    LowPower,
+
+    /// The MCU is off and will only wake on a reset.
+    Off,
+}
+
+impl McuPowerState {
+    const MCU_POWER_STATE_ON: u8 = 0;
+    const MCU_POWER_STATE_LOW_POWER: u8 = 1;
+    const MCU_POWER_STATE_OFF: u8 = 2;
+
+    const POWER_STATE_OFFLINE: u8 = 0;
+    const POWER_STATE_DEEP_SLEEP: u8 = 1;
+    const POWER_STATE_STANDBY: u8 = 2;
+    const POWER_STATE_LOW_POWER: u8 = 3;
+    const POWER_STATE_ONLINE: u8 = 4;
+
+    /// Decode the value of the deprecated [`Property::PowerState`](crate::Property::PowerState).
+    ///
+    /// Older firmware only reports this property, which uses a different set of values. Offline maps to
+    /// [`McuPowerState::Off`], online to [`McuPowerState::On`], and the sleep and standby states to
+    /// [`McuPowerState::LowPower`].
+    pub fn decode_legacy(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = u8::decode(bytes)?;
+
+        let state = match value {
+            Self::POWER_STATE_OFFLINE => Self::Off,
+            Self::POWER_STATE_DEEP_SLEEP
+            | Self::POWER_STATE_STANDBY
+            | Self::POWER_STATE_LOW_POWER => Self::LowPower,
+            Self::POWER_STATE_ONLINE => Self::On,
+            _ => return Err(Error::UnknownPowerState(value)),
+        };
+
+        Ok((state, count))
+    }
+}
+
+impl fmt::Display for McuPowerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            McuPowerState::On => write!(f, "On"),
+            McuPowerState::LowPower => write!(f, "LowPower"),
+            McuPowerState::Off => write!(f, "Off"),
+        }
+    }
+}
+
+impl TryFrom<u8> for McuPowerState {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            Self::MCU_POWER_STATE_ON => Ok(Self::On),
+            Self::MCU_POWER_STATE_LOW_POWER => Ok(Self::LowPower),
+            Self::MCU_POWER_STATE_OFF => Ok(Self::Off),
+            _ => Err(Error::UnknownPowerState(value)),
+        }
+    }
+}
+
+impl From<McuPowerState> for u8 {
+    fn from(state: McuPowerState) -> u8 {
+        match state {
+            McuPowerState::On => McuPowerState::MCU_POWER_STATE_ON,
+            McuPowerState::LowPower => McuPowerState::MCU_POWER_STATE_LOW_POWER,
+            McuPowerState::Off => McuPowerState::MCU_POWER_STATE_OFF,
+        }
+    }
+}
+
+impl<'a> Decode<'a> for McuPowerState {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = u8::decode(bytes)?;
+        Ok((Self::try_from(value)?, count))
+    }
+}
+
+impl Encode for McuPowerState {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        u8::from(*self).encode(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_power_state_round_trip() {
+        for (value, state) in [
+            (0, HostPowerState::Offline),
+            (1, HostPowerState::DeepSleep),
+            (2, HostPowerState::Reserved),
+            (3, HostPowerState::LowPower),
+            (4, HostPowerState::Online),
+        ] {
+            assert_eq!(HostPowerState::decode(&[value]), Ok((state, 1)));
+            assert_eq!(u8::from(state), value);
+        }
+
+        assert_eq!(
+            HostPowerState::decode(&[5]),
+            Err(Error::UnknownPowerState(5))
+        );
+    }
+
+    #[test]
+    fn mcu_power_state_round_trip() {
+        for (value, state) in [
+            (0, McuPowerState::On),
+            (1, McuPowerState::LowPower),
+            (2, McuPowerState::Off),
+        ] {
+            assert_eq!(McuPowerState::decode(&[value]), Ok((state, 1)));
+            assert_eq!(u8::from(state), value);
+        }
+
+        assert_eq!(
+            McuPowerState::decode(&[3]),
+            Err(Error::UnknownPowerState(3))
+        );
+    }
+
+    #[test]
+    fn mcu_power_state_from_legacy() {
+        for (value, state) in [
+            (0, McuPowerState::Off),
+            (1, McuPowerState::LowPower),
+            (2, McuPowerState::LowPower),
+            (3, McuPowerState::LowPower),
+            (4, McuPowerState::On),
+        ] {
+            assert_eq!(McuPowerState::decode_legacy(&[value]), Ok((state, 1)));
+        }
+
+        assert_eq!(
+            McuPowerState::decode_legacy(&[5]),
+            Err(Error::UnknownPowerState(5))
+        );
+        assert_eq!(
+            McuPowerState::decode_legacy(&[]),
+            Err(Error::PacketLength(0))
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Spinel primitive datatypes.
+//!
+//! | Format | Type    |
+//! |--------|---------|
+//! | `b`    | [`bool`] |
+//! | `C`    | [`u8`]  |
+//! | `c`    | [`i8`]  |
+//! | `S`    | [`u16`] |
+//! | `s`    | [`i16`] |
+//! | `L`    | [`u32`] |
+//! | `l`    | [`i32`] |
+//! | `U`    | [`str`] |
+//! | `d`    | `[u8]`  |
+//!
+//! Multi-byte integers are little-endian on the wire. Strings are UTF-8 and NUL terminated. Data blobs are
+//! prefixed with their length as a `uint16`.
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+
+impl<'a> Decode<'a> for bool {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = u8::decode(bytes)?;
+        Ok((value != 0, count))
+    }
+}
+
+impl Encode for bool {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        u8::from(*self).encode(buffer)
+    }
+}
+
+macro_rules! impl_fixed_width {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> Decode<'a> for $ty {
+                fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+                    const LEN: usize = core::mem::size_of::<$ty>();
+
+                    match bytes.get(..LEN) {
+                        Some(value) => {
+                            let mut array = [0; LEN];
+                            array.copy_from_slice(value);
+                            Ok((<$ty>::from_le_bytes(array), LEN))
+                        }
+                        None => Err(Error::PacketLength(bytes.len())),
+                    }
+                }
+            }
+
+            impl Encode for $ty {
+                fn encode(&self, buffer: &mut BytesMut) -> usize {
+                    buffer.put_slice(&self.to_le_bytes());
+                    core::mem::size_of::<$ty>()
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_width!(u8, i8, u16, i16, u32, i32);
+
+impl<'a> Decode<'a> for &'a str {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let len = bytes
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or(Error::PacketLength(bytes.len()))?;
+        let value = core::str::from_utf8(&bytes[..len])?;
+
+        // Include the NUL terminator in the count
+        Ok((value, len + 1))
+    }
+}
+
+impl Encode for &str {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        buffer.put_slice(self.as_bytes());
+        buffer.put_u8(0);
+        self.len() + 1
+    }
+}
+
+impl<'a> Decode<'a> for &'a [u8] {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (len, count) = u16::decode(bytes)?;
+        let value = bytes
+            .get(count..count + len as usize)
+            .ok_or(Error::PacketLength(bytes.len()))?;
+
+        Ok((value, count + value.len()))
+    }
+}
+
+impl Encode for &[u8] {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        debug_assert!(
+            self.len() <= u16::MAX as usize,
+            "data too long for a uint16 length"
+        );
+        let count = (self.len() as u16).encode(buffer);
+        buffer.put_slice(self);
+        count + self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T>(value: T, wire: &[u8])
+    where
+        T: for<'a> Decode<'a> + Encode + PartialEq + core::fmt::Debug,
+    {
+        let mut buffer = BytesMut::new();
+        assert_eq!(value.encode(&mut buffer), wire.len());
+        assert_eq!(&buffer[..], wire);
+        assert_eq!(T::decode(wire), Ok((value, wire.len())));
+    }
+
+    #[test]
+    fn bool_round_trip() {
+        round_trip(true, &[0x01]);
+        round_trip(false, &[0x00]);
+    }
+
+    #[test]
+    fn integer_round_trip() {
+        round_trip(0xA5u8, &[0xA5]);
+        round_trip(-2i8, &[0xFE]);
+        round_trip(0x1234u16, &[0x34, 0x12]);
+        round_trip(-2i16, &[0xFE, 0xFF]);
+        round_trip(0x1234_5678u32, &[0x78, 0x56, 0x34, 0x12]);
+        round_trip(-2i32, &[0xFE, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn decode_consumes_from_front() {
+        let bytes = [0x34, 0x12, 0xFF];
+        assert_eq!(u16::decode(&bytes), Ok((0x1234, 2)));
+    }
+
+    #[test]
+    fn decode_fails_on_short_buffer() {
+        assert_eq!(u32::decode(&[0x01, 0x02]), Err(Error::PacketLength(2)));
+        assert_eq!(bool::decode(&[]), Err(Error::PacketLength(0)));
+    }
+
+    #[test]
+    fn utf8_round_trip() {
+        let wire = b"thread\0";
+        let mut buffer = BytesMut::new();
+        assert_eq!("thread".encode(&mut buffer), wire.len());
+        assert_eq!(&buffer[..], wire);
+        assert_eq!(<&str>::decode(wire), Ok(("thread", wire.len())));
+    }
+
+    #[test]
+    fn data_round_trip() {
+        let wire = [0x03, 0x00, 0xDE, 0xAD, 0xBE];
+        let mut buffer = BytesMut::new();
+        assert_eq!((&wire[2..]).encode(&mut buffer), wire.len());
+        assert_eq!(&buffer[..], &wire);
+        assert_eq!(<&[u8]>::decode(&wire), Ok((&wire[2..], wire.len())));
+        assert_eq!(<&[u8]>::decode(&wire[..4]), Err(Error::PacketLength(4)));
+    }
+
+    #[test]
+    fn utf8_requires_terminator() {
+        assert_eq!(<&str>::decode(b"thread"), Err(Error::PacketLength(6)));
+    }
+
+    #[test]
+    fn utf8_rejects_invalid_characters() {
+        assert!(matches!(
+            <&str>::decode(&[0xFF, 0x00]),
+            Err(Error::DatatypeParseU8(_))
+        ));
+    }
+}
@@ -0,0 +1,124 @@
+use super::{Decode, Encode, PackedU32};
+use crate::Error;
+use bytes::BytesMut;
+use core::fmt;
+
+/// The Spinel protocol version implemented by the device, as reported by
+/// [`Property::ProtocolVersion`](crate::Property::ProtocolVersion).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProtocolVersion {
+    /// Major version, incremented on incompatible changes.
+    pub major: u32,
+
+    /// Minor version, incremented on backwards compatible changes.
+    pub minor: u32,
+}
+
+impl ProtocolVersion {
+    /// The major version of the Spinel protocol implemented by this crate.
+    pub const SUPPORTED_MAJOR: u32 = 4;
+
+    /// Create a new [`ProtocolVersion`].
+    pub fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Check if the major version matches [`ProtocolVersion::SUPPORTED_MAJOR`].
+    ///
+    /// Any minor version is accepted, as minor versions only add backwards compatible features.
+    pub fn is_compatible(&self) -> bool {
+        self.major == Self::SUPPORTED_MAJOR
+    }
+
+    /// Check that the device speaks a protocol version this crate can parse.
+    ///
+    /// Returns [`Error::IncompatibleProtocol`] otherwise, so a mismatched firmware is reported up front rather than
+    /// through confusing decode errors later on.
+    pub fn ensure_compatible(&self) -> Result<(), Error> {
+        if !self.is_compatible() {
+            return Err(Error::IncompatibleProtocol(*self));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl<'a> Decode<'a> for ProtocolVersion {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (major, major_len) = PackedU32::decode(bytes);
+        if major_len == 0 {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
+        let (minor, minor_len) = PackedU32::decode(&bytes[major_len..]);
+        if minor_len == 0 {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
+        Ok((Self { major, minor }, major_len + minor_len))
+    }
+}
+
+impl Encode for ProtocolVersion {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        PackedU32::write_to_buffer(self.major, buffer)
+            + PackedU32::write_to_buffer(self.minor, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_protocol_version() {
+        assert_eq!(
+            ProtocolVersion::decode(&[0x04, 0x03]),
+            Ok((ProtocolVersion::new(4, 3), 2))
+        );
+    }
+
+    #[test]
+    fn decode_protocol_version_missing_minor() {
+        assert_eq!(
+            ProtocolVersion::decode(&[0x04]),
+            Err(Error::PacketLength(1))
+        );
+    }
+
+    #[test]
+    fn encode_protocol_version() {
+        let mut buffer = BytesMut::new();
+        assert_eq!(ProtocolVersion::new(4, 3).encode(&mut buffer), 2);
+        assert_eq!(&buffer[..], &[0x04, 0x03]);
+    }
+
+    #[test]
+    fn compatible_protocol_version() {
+        assert!(ProtocolVersion::new(4, 0).is_compatible());
+        assert!(ProtocolVersion::new(4, 3).is_compatible());
+        assert_eq!(ProtocolVersion::new(4, 3).ensure_compatible(), Ok(()));
+    }
+
+    #[test]
+    fn incompatible_protocol_version() {
+        for version in [ProtocolVersion::new(3, 9), ProtocolVersion::new(5, 0)] {
+            assert!(!version.is_compatible());
+            assert_eq!(
+                version.ensure_compatible(),
+                Err(Error::IncompatibleProtocol(version))
+            );
+        }
+    }
+
+    #[test]
+    fn display_protocol_version() {
+        assert_eq!(ProtocolVersion::new(4, 3).to_string(), "4.3");
+    }
+}
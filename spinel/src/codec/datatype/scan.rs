@@ -0,0 +1,149 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, BytesMut};
+use core::fmt;
+
+/// The scan state of the device, as reported by [`Property::MacScanState`](crate::Property::MacScanState).
+///
+/// Setting the property to anything other than [`ScanState::Idle`] starts a scan, the device sets it back to
+/// [`ScanState::Idle`] once the scan has finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScanState {
+    /// No scan is in progress.
+    Idle,
+
+    /// Scanning for 802.15.4 beacons.
+    Beacon,
+
+    /// Measuring the energy on each channel.
+    Energy,
+
+    /// Scanning for Thread networks with discovery requests.
+    Discover,
+
+    /// A scan state this crate does not recognize.
+    Unknown(u8),
+}
+
+impl ScanState {
+    const SCAN_STATE_IDLE: u8 = 0;
+    const SCAN_STATE_BEACON: u8 = 1;
+    const SCAN_STATE_ENERGY: u8 = 2;
+    const SCAN_STATE_DISCOVER: u8 = 3;
+}
+
+impl fmt::Display for ScanState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanState::Idle => write!(f, "Idle"),
+            ScanState::Beacon => write!(f, "Beacon"),
+            ScanState::Energy => write!(f, "Energy"),
+            ScanState::Discover => write!(f, "Discover"),
+            ScanState::Unknown(state) => write!(f, "Unknown({})", state),
+        }
+    }
+}
+
+impl From<u8> for ScanState {
+    fn from(value: u8) -> Self {
+        match value {
+            Self::SCAN_STATE_IDLE => Self::Idle,
+            Self::SCAN_STATE_BEACON => Self::Beacon,
+            Self::SCAN_STATE_ENERGY => Self::Energy,
+            Self::SCAN_STATE_DISCOVER => Self::Discover,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<ScanState> for u8 {
+    fn from(value: ScanState) -> u8 {
+        match value {
+            ScanState::Idle => ScanState::SCAN_STATE_IDLE,
+            ScanState::Beacon => ScanState::SCAN_STATE_BEACON,
+            ScanState::Energy => ScanState::SCAN_STATE_ENERGY,
+            ScanState::Discover => ScanState::SCAN_STATE_DISCOVER,
+            ScanState::Unknown(state) => state,
+        }
+    }
+}
+
+impl<'a> Decode<'a> for ScanState {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (value, count) = u8::decode(bytes)?;
+        Ok((Self::from(value), count))
+    }
+}
+
+impl Encode for ScanState {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        u8::from(*self).encode(buffer)
+    }
+}
+
+/// A single result of an energy scan, as reported by
+/// [`Property::MacEnergyScanResult`](crate::Property::MacEnergyScanResult).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnergyScanResult {
+    /// The channel that was scanned.
+    pub channel: u8,
+
+    /// The maximum RSSI measured on the channel, in dBm.
+    pub rssi: i8,
+}
+
+impl<'a> Decode<'a> for EnergyScanResult {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (channel, channel_len) = u8::decode(bytes)?;
+        let (rssi, rssi_len) = i8::decode(&bytes[channel_len..])?;
+
+        Ok((Self { channel, rssi }, channel_len + rssi_len))
+    }
+}
+
+impl Encode for EnergyScanResult {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        buffer.put_u8(self.channel);
+        buffer.put_i8(self.rssi);
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_state_round_trip() {
+        for (value, state) in [
+            (0, ScanState::Idle),
+            (1, ScanState::Beacon),
+            (2, ScanState::Energy),
+            (3, ScanState::Discover),
+            (9, ScanState::Unknown(9)),
+        ] {
+            assert_eq!(ScanState::decode(&[value]), Ok((state, 1)));
+            assert_eq!(u8::from(state), value);
+        }
+    }
+
+    #[test]
+    fn decode_energy_scan_result() {
+        let result = EnergyScanResult::decode(&[0x0F, 0xB5]);
+        assert_eq!(
+            result,
+            Ok((
+                EnergyScanResult {
+                    channel: 15,
+                    rssi: -75,
+                },
+                2
+            ))
+        );
+
+        assert_eq!(
+            EnergyScanResult::decode(&[0x0F]),
+            Err(Error::PacketLength(0))
+        );
+    }
+}
@@ -4,7 +4,10 @@ use core::fmt;
 ///
 /// Status codes sent from the device to the host via [`Property::LastStatus`](crate::codec::Property). Status codes
 /// represent the result of the last command executed by the device.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Statuses are ordered by variant: the known statuses in order of their code, then [`Status::Reserved`] and
+/// [`Status::Vendor`] by code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Status {
     /// The operation has completed successfully.
     Ok,
@@ -80,34 +83,50 @@ pub enum Status {
 
     /// No response received from the remote within the timeout period.
     ResponseTimeout,
+
+    /// A status code this crate does not recognize.
+    ///
+    /// Codes outside of the ones defined above and the vendor range are reserved by the specification and kept as
+    /// is, so they can be logged and sent back unchanged.
+    Reserved(u32),
+
+    /// A vendor specific status code in the vendor range (`0x3C00` to `0x3FFF`).
+    Vendor(u32),
 }
 
 impl Status {
-    const STATUS_OK: u8 = 0;
-    const STATUS_FAILURE: u8 = 1;
-    const STATUS_UNIMPLEMENTED: u8 = 2;
-    const STATUS_INVALID_ARGUMENT: u8 = 3;
-    const STATUS_INVALID_STATE: u8 = 4;
-    const STATUS_INVALID_COMMAND: u8 = 5;
-    const STATUS_INVALID_INTERFACE: u8 = 6;
-    const STATUS_INTERNAL_ERROR: u8 = 7;
-    const STATUS_SECURITY_ERROR: u8 = 8;
-    const STATUS_PARSE_ERROR: u8 = 9;
-    const STATUS_IN_PROGRESS: u8 = 10;
-    const STATUS_NO_MEMORY: u8 = 11;
-    const STATUS_BUSY: u8 = 12;
-    const STATUS_PROPERTY_NOT_FOUND: u8 = 13;
-    const STATUS_PACKET_DROPPED: u8 = 14;
-    const STATUS_EMPTY: u8 = 15;
-    const STATUS_COMMAND_TOO_BIG: u8 = 16;
-    const STATUS_NO_ACK: u8 = 17;
-    const STATUS_CCA_FAILURE: u8 = 18;
-    const STATUS_ALREADY: u8 = 19;
-    const STATUS_ITEM_NOT_FOUND: u8 = 20;
-    const STATUS_INVALID_COMMAND_FOR_PROPERTY: u8 = 21;
-    const STATUS_UNKNOWN_NEIGHBOR: u8 = 22;
-    const STATUS_NOT_CAPABLE: u8 = 23;
-    const STATUS_RESPONSE_TIMEOUT: u8 = 24;
+    const STATUS_OK: u32 = 0;
+    const STATUS_FAILURE: u32 = 1;
+    const STATUS_UNIMPLEMENTED: u32 = 2;
+    const STATUS_INVALID_ARGUMENT: u32 = 3;
+    const STATUS_INVALID_STATE: u32 = 4;
+    const STATUS_INVALID_COMMAND: u32 = 5;
+    const STATUS_INVALID_INTERFACE: u32 = 6;
+    const STATUS_INTERNAL_ERROR: u32 = 7;
+    const STATUS_SECURITY_ERROR: u32 = 8;
+    const STATUS_PARSE_ERROR: u32 = 9;
+    const STATUS_IN_PROGRESS: u32 = 10;
+    const STATUS_NO_MEMORY: u32 = 11;
+    const STATUS_BUSY: u32 = 12;
+    const STATUS_PROPERTY_NOT_FOUND: u32 = 13;
+    const STATUS_PACKET_DROPPED: u32 = 14;
+    const STATUS_EMPTY: u32 = 15;
+    const STATUS_COMMAND_TOO_BIG: u32 = 16;
+    const STATUS_NO_ACK: u32 = 17;
+    const STATUS_CCA_FAILURE: u32 = 18;
+    const STATUS_ALREADY: u32 = 19;
+    const STATUS_ITEM_NOT_FOUND: u32 = 20;
+    const STATUS_INVALID_COMMAND_FOR_PROPERTY: u32 = 21;
+    const STATUS_UNKNOWN_NEIGHBOR: u32 = 22;
+    const STATUS_NOT_CAPABLE: u32 = 23;
+    const STATUS_RESPONSE_TIMEOUT: u32 = 24;
+    const STATUS_VENDOR_BEGIN: u32 = 0x3C00;
+    const STATUS_VENDOR_END: u32 = 0x4000;
+
+    /// The numeric code of the status on the wire.
+    pub fn code(&self) -> u32 {
+        u32::from(*self)
+    }
 }
 
 impl fmt::Display for Status {
@@ -138,47 +157,48 @@ impl fmt::Display for Status {
             Status::UnknownNeighbor => write!(f, "UnknownNeighbor"),
             Status::NotCapable => write!(f, "NotCapable"),
             Status::ResponseTimeout => write!(f, "ResponseTimeout"),
+            Status::Reserved(code) => write!(f, "Reserved({})", code),
+            Status::Vendor(code) => write!(f, "Vendor({})", code),
         }
     }
 }
 
-impl TryFrom<u8> for Status {
-    type Error = ();
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
+impl From<u32> for Status {
+    fn from(value: u32) -> Self {
         match value {
-            Self::STATUS_OK => Ok(Self::Ok),
-            Self::STATUS_FAILURE => Ok(Self::Failure),
-            Self::STATUS_UNIMPLEMENTED => Ok(Self::Unimplemented),
-            Self::STATUS_INVALID_ARGUMENT => Ok(Self::InvalidArgument),
-            Self::STATUS_INVALID_STATE => Ok(Self::InvalidState),
-            Self::STATUS_INVALID_COMMAND => Ok(Self::InvalidCommand),
-            Self::STATUS_INVALID_INTERFACE => Ok(Self::InvalidInterface),
-            Self::STATUS_INTERNAL_ERROR => Ok(Self::InternalError),
-            Self::STATUS_SECURITY_ERROR => Ok(Self::SecurityError),
-            Self::STATUS_PARSE_ERROR => Ok(Self::ParseError),
-            Self::STATUS_IN_PROGRESS => Ok(Self::InProgress),
-            Self::STATUS_NO_MEMORY => Ok(Self::NoMemory),
-            Self::STATUS_BUSY => Ok(Self::Busy),
-            Self::STATUS_PROPERTY_NOT_FOUND => Ok(Self::PropertyNotFound),
-            Self::STATUS_PACKET_DROPPED => Ok(Self::PacketDropped),
-            Self::STATUS_EMPTY => Ok(Self::Empty),
-            Self::STATUS_COMMAND_TOO_BIG => Ok(Self::CommandTooBig),
-            Self::STATUS_NO_ACK => Ok(Self::NoAck),
-            Self::STATUS_CCA_FAILURE => Ok(Self::CcaFailure),
-            Self::STATUS_ALREADY => Ok(Self::Already),
-            Self::STATUS_ITEM_NOT_FOUND => Ok(Self::ItemNotFound),
-            Self::STATUS_INVALID_COMMAND_FOR_PROPERTY => Ok(Self::InvalidCommandForProperty),
-            Self::STATUS_UNKNOWN_NEIGHBOR => Ok(Self::UnknownNeighbor),
-            Self::STATUS_NOT_CAPABLE => Ok(Self::NotCapable),
-            Self::STATUS_RESPONSE_TIMEOUT => Ok(Self::ResponseTimeout),
-            _ => Err(()),
+            Self::STATUS_OK => Self::Ok,
+            Self::STATUS_FAILURE => Self::Failure,
+            Self::STATUS_UNIMPLEMENTED => Self::Unimplemented,
+            Self::STATUS_INVALID_ARGUMENT => Self::InvalidArgument,
+            Self::STATUS_INVALID_STATE => Self::InvalidState,
+            Self::STATUS_INVALID_COMMAND => Self::InvalidCommand,
+            Self::STATUS_INVALID_INTERFACE => Self::InvalidInterface,
+            Self::STATUS_INTERNAL_ERROR => Self::InternalError,
+            Self::STATUS_SECURITY_ERROR => Self::SecurityError,
+            Self::STATUS_PARSE_ERROR => Self::ParseError,
+            Self::STATUS_IN_PROGRESS => Self::InProgress,
+            Self::STATUS_NO_MEMORY => Self::NoMemory,
+            Self::STATUS_BUSY => Self::Busy,
+            Self::STATUS_PROPERTY_NOT_FOUND => Self::PropertyNotFound,
+            Self::STATUS_PACKET_DROPPED => Self::PacketDropped,
+            Self::STATUS_EMPTY => Self::Empty,
+            Self::STATUS_COMMAND_TOO_BIG => Self::CommandTooBig,
+            Self::STATUS_NO_ACK => Self::NoAck,
+            Self::STATUS_CCA_FAILURE => Self::CcaFailure,
+            Self::STATUS_ALREADY => Self::Already,
+            Self::STATUS_ITEM_NOT_FOUND => Self::ItemNotFound,
+            Self::STATUS_INVALID_COMMAND_FOR_PROPERTY => Self::InvalidCommandForProperty,
+            Self::STATUS_UNKNOWN_NEIGHBOR => Self::UnknownNeighbor,
+            Self::STATUS_NOT_CAPABLE => Self::NotCapable,
+            Self::STATUS_RESPONSE_TIMEOUT => Self::ResponseTimeout,
+            Self::STATUS_VENDOR_BEGIN..Self::STATUS_VENDOR_END => Self::Vendor(value),
+            _ => Self::Reserved(value),
         }
     }
 }
 
-impl From<Status> for u8 {
-    fn from(status: Status) -> u8 {
+impl From<Status> for u32 {
+    fn from(status: Status) -> u32 {
         match status {
             Status::Ok => Status::STATUS_OK,
             Status::Failure => Status::STATUS_FAILURE,
@@ -205,11 +225,13 @@ impl From<Status> for u8 {
             Status::UnknownNeighbor => Status::STATUS_UNKNOWN_NEIGHBOR,
             Status::NotCapable => Status::STATUS_NOT_CAPABLE,
             Status::ResponseTimeout => Status::STATUS_RESPONSE_TIMEOUT,
+            Status::Reserved(code) | Status::Vendor(code) => code,
         }
     }
 }
 
 /// Reasons that a device has reset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ResetReason {
     PowerOn,
     External,
@@ -220,35 +242,107 @@ pub enum ResetReason {
     Other,
     Unknown,
     Watchdog,
+
+    /// A reset code outside of the ones defined above, such as a platform or vendor specific reason.
+    PlatformSpecific(u32),
 }
 
 impl ResetReason {
-    const RESET_POWER_ON: u8 = 112;
-    const RESET_EXTERNAL: u8 = 113;
-    const RESET_SOFTWARE: u8 = 114;
-    const RESET_FAULT: u8 = 115;
-    const RESET_CRASH: u8 = 116;
-    const RESET_ASSERT: u8 = 117;
-    const RESET_OTHER: u8 = 118;
-    const RESET_UNKNOWN: u8 = 119;
-    const RESET_WATCHDOG: u8 = 120;
+    const RESET_POWER_ON: u32 = 112;
+    const RESET_EXTERNAL: u32 = 113;
+    const RESET_SOFTWARE: u32 = 114;
+    const RESET_FAULT: u32 = 115;
+    const RESET_CRASH: u32 = 116;
+    const RESET_ASSERT: u32 = 117;
+    const RESET_OTHER: u32 = 118;
+    const RESET_UNKNOWN: u32 = 119;
+    const RESET_WATCHDOG: u32 = 120;
 }
 
-impl TryFrom<u8> for ResetReason {
-    type Error = ();
-
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
+impl From<u32> for ResetReason {
+    fn from(value: u32) -> Self {
         match value {
-            Self::RESET_POWER_ON => Ok(Self::PowerOn),
-            Self::RESET_EXTERNAL => Ok(Self::External),
-            Self::RESET_SOFTWARE => Ok(Self::Software),
-            Self::RESET_FAULT => Ok(Self::Fault),
-            Self::RESET_CRASH => Ok(Self::Crash),
-            Self::RESET_ASSERT => Ok(Self::Assert),
-            Self::RESET_OTHER => Ok(Self::Other),
-            Self::RESET_UNKNOWN => Ok(Self::Unknown),
-            Self::RESET_WATCHDOG => Ok(Self::Watchdog),
-            _ => Err(()),
+            Self::RESET_POWER_ON => Self::PowerOn,
+            Self::RESET_EXTERNAL => Self::External,
+            Self::RESET_SOFTWARE => Self::Software,
+            Self::RESET_FAULT => Self::Fault,
+            Self::RESET_CRASH => Self::Crash,
+            Self::RESET_ASSERT => Self::Assert,
+            Self::RESET_OTHER => Self::Other,
+            Self::RESET_UNKNOWN => Self::Unknown,
+            Self::RESET_WATCHDOG => Self::Watchdog,
+            _ => Self::PlatformSpecific(value),
         }
     }
 }
+
+impl From<ResetReason> for u32 {
+    fn from(reason: ResetReason) -> u32 {
+        match reason {
+            ResetReason::PowerOn => ResetReason::RESET_POWER_ON,
+            ResetReason::External => ResetReason::RESET_EXTERNAL,
+            ResetReason::Software => ResetReason::RESET_SOFTWARE,
+            ResetReason::Fault => ResetReason::RESET_FAULT,
+            ResetReason::Crash => ResetReason::RESET_CRASH,
+            ResetReason::Assert => ResetReason::RESET_ASSERT,
+            ResetReason::Other => ResetReason::RESET_OTHER,
+            ResetReason::Unknown => ResetReason::RESET_UNKNOWN,
+            ResetReason::Watchdog => ResetReason::RESET_WATCHDOG,
+            ResetReason::PlatformSpecific(code) => code,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_code_round_trip() {
+        for code in (0..=0x4100).chain([u32::MAX]) {
+            assert_eq!(Status::from(code).code(), code);
+        }
+    }
+
+    #[test]
+    fn status_from_code() {
+        assert_eq!(Status::from(0), Status::Ok);
+        assert_eq!(Status::from(12), Status::Busy);
+        assert_eq!(Status::from(24), Status::ResponseTimeout);
+        assert_eq!(Status::from(25), Status::Reserved(25));
+        assert_eq!(Status::from(0x3BFF), Status::Reserved(0x3BFF));
+        assert_eq!(Status::from(0x3C00), Status::Vendor(0x3C00));
+        assert_eq!(Status::from(0x3FFF), Status::Vendor(0x3FFF));
+        assert_eq!(Status::from(0x4000), Status::Reserved(0x4000));
+    }
+
+    #[test]
+    fn status_ordering() {
+        assert!(Status::Ok < Status::Failure);
+        assert!(Status::Busy < Status::ResponseTimeout);
+        assert!(Status::ResponseTimeout < Status::Reserved(25));
+        assert!(Status::Reserved(25) < Status::Reserved(26));
+        assert!(Status::Reserved(0x4000) < Status::Vendor(0x3C00));
+        assert_eq!(Status::Busy.max(Status::Ok), Status::Busy);
+    }
+
+    #[test]
+    fn reset_reason_from_code() {
+        assert_eq!(ResetReason::from(112), ResetReason::PowerOn);
+        assert_eq!(ResetReason::from(120), ResetReason::Watchdog);
+        assert_eq!(ResetReason::from(200), ResetReason::PlatformSpecific(200));
+        assert_eq!(u32::from(ResetReason::PlatformSpecific(200)), 200);
+    }
+
+    #[test]
+    fn reset_reason_from_packed_code() {
+        use crate::codec::PackedU32;
+
+        let (code, _) = PackedU32::decode_unbounded(&[0x81, 0x78]).unwrap();
+        assert_eq!(
+            ResetReason::from(code),
+            ResetReason::PlatformSpecific(0x3C01)
+        );
+        assert_eq!(u32::from(ResetReason::PlatformSpecific(0x3C01)), 0x3C01);
+    }
+}
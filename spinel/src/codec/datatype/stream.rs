@@ -0,0 +1,175 @@
+use super::{Decode, Encode};
+use crate::Error;
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// Metadata sent after the packet in a network stream frame.
+///
+/// On the wire this is `ccSt(CC)`: RSSI, noise floor, flags and a struct of PHY data starting with the channel and
+/// LQI. Devices may leave off trailing fields, so everything after the RSSI is optional. Decoding stops at the
+/// first field that is missing and encoding stops at the first field that is `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StreamMetadata {
+    /// The RSSI of a received packet in dBm, or the transmit power for a sent packet.
+    pub rssi: i8,
+
+    /// The noise floor in dBm.
+    pub noise_floor: Option<i8>,
+
+    /// Protocol specific flags.
+    pub flags: Option<u16>,
+
+    /// The channel the packet was received on, or should be sent on.
+    pub channel: Option<u8>,
+
+    /// The link quality indicator of a received packet.
+    pub lqi: Option<u8>,
+}
+
+impl<'a> Decode<'a> for StreamMetadata {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        let (rssi, mut count) = i8::decode(bytes)?;
+        let mut metadata = StreamMetadata {
+            rssi,
+            ..Default::default()
+        };
+
+        let Ok((noise_floor, len)) = i8::decode(&bytes[count..]) else {
+            return Ok((metadata, count));
+        };
+        metadata.noise_floor = Some(noise_floor);
+        count += len;
+
+        let Ok((flags, len)) = u16::decode(&bytes[count..]) else {
+            return Ok((metadata, count));
+        };
+        metadata.flags = Some(flags);
+        count += len;
+
+        let Ok((phy, len)) = <&[u8]>::decode(&bytes[count..]) else {
+            return Ok((metadata, count));
+        };
+        count += len;
+
+        metadata.channel = phy.first().copied();
+        metadata.lqi = phy.get(1).copied();
+
+        Ok((metadata, count))
+    }
+}
+
+impl Encode for StreamMetadata {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        let mut count = self.rssi.encode(buffer);
+
+        let Some(noise_floor) = self.noise_floor else {
+            return count;
+        };
+        count += noise_floor.encode(buffer);
+
+        let Some(flags) = self.flags else {
+            return count;
+        };
+        count += flags.encode(buffer);
+
+        let Some(channel) = self.channel else {
+            return count;
+        };
+
+        let mut phy = BytesMut::with_capacity(2);
+        phy.put_u8(channel);
+        if let Some(lqi) = self.lqi {
+            phy.put_u8(lqi);
+        }
+
+        count + (&phy[..]).encode(buffer)
+    }
+}
+
+/// The value of a network stream property: a `uint16` length prefixed packet followed by optional
+/// [`StreamMetadata`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetStreamFrame {
+    /// The network packet.
+    pub data: Bytes,
+
+    /// The metadata sent after the packet, if any.
+    pub metadata: Option<StreamMetadata>,
+}
+
+impl NetStreamFrame {
+    /// Decode a [`NetStreamFrame`] from the value of a stream property.
+    ///
+    /// The packet data is sliced from the payload without copying.
+    pub fn decode(payload: &Bytes) -> Result<Self, Error> {
+        let (data, count) = <&[u8]>::decode(payload)?;
+        let data = payload.slice(count - data.len()..count);
+
+        let metadata = match payload.len() > count {
+            true => Some(StreamMetadata::decode(&payload[count..])?.0),
+            false => None,
+        };
+
+        Ok(Self { data, metadata })
+    }
+
+    /// Encode the [`NetStreamFrame`] and write it to a buffer.
+    ///
+    /// Returns [`Error::PacketLength`] if the packet is too large for the length field.
+    pub fn encode(&self, buffer: &mut BytesMut) -> Result<usize, Error> {
+        if self.data.len() > u16::MAX as usize {
+            return Err(Error::PacketLength(self.data.len()));
+        }
+
+        let mut count = (&self.data[..]).encode(buffer);
+        if let Some(metadata) = &self.metadata {
+            count += metadata.encode(buffer);
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_without_metadata() {
+        let payload = Bytes::from_static(&[0x02, 0x00, 0x60, 0x00]);
+        let frame = NetStreamFrame::decode(&payload).unwrap();
+        assert_eq!(frame.data, Bytes::from_static(&[0x60, 0x00]));
+        assert_eq!(frame.metadata, None);
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let frame = NetStreamFrame {
+            data: Bytes::from_static(&[0x60, 0x00]),
+            metadata: Some(StreamMetadata {
+                rssi: -70,
+                noise_floor: Some(-100),
+                flags: Some(0x0001),
+                channel: Some(15),
+                lqi: Some(0xFF),
+            }),
+        };
+
+        let wire = [
+            0x02, 0x00, 0x60, 0x00, 0xBA, 0x9C, 0x01, 0x00, 0x02, 0x00, 0x0F, 0xFF,
+        ];
+
+        let mut buffer = BytesMut::new();
+        assert_eq!(frame.encode(&mut buffer), Ok(wire.len()));
+        assert_eq!(&buffer[..], &wire);
+        assert_eq!(NetStreamFrame::decode(&buffer.freeze()), Ok(frame));
+    }
+
+    #[test]
+    fn decode_truncated_data() {
+        let payload = Bytes::from_static(&[0x04, 0x00, 0x60, 0x00]);
+        assert_eq!(
+            NetStreamFrame::decode(&payload),
+            Err(Error::PacketLength(4))
+        );
+    }
+}
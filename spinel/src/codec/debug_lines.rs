@@ -0,0 +1,122 @@
+use crate::{Frame, PropertyStream};
+
+/// Reassemble the text sent on [`PropertyStream::Debug`] into lines.
+///
+/// The device inserts newlines where it needs them, but a line may be split across several frames. Payloads are
+/// buffered with [`DebugLines::push`] or [`DebugLines::push_frame`] and complete lines are taken out with
+/// [`DebugLines::next_line`].
+#[derive(Debug, Default)]
+pub struct DebugLines {
+    buffer: Vec<u8>,
+}
+
+impl DebugLines {
+    /// Create a new, empty [`DebugLines`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push the payload of a debug stream frame.
+    pub fn push(&mut self, payload: &[u8]) {
+        self.buffer.extend_from_slice(payload);
+    }
+
+    /// Push the payload of a [`Frame`] if it is a [`PropertyStream::Debug`] frame.
+    ///
+    /// Returns `false` and ignores the frame if it is not.
+    pub fn push_frame(&mut self, frame: &Frame) -> bool {
+        match frame.stream_payload() {
+            Some((PropertyStream::Debug, payload)) => {
+                self.push(&payload);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Take the next complete line, without its `\n` or `\r\n` terminator.
+    ///
+    /// Invalid UTF-8 is replaced with `U+FFFD`. Returns `None` until a full line has been pushed.
+    pub fn next_line(&mut self) -> Option<String> {
+        let end = self.buffer.iter().position(|&byte| byte == b'\n')?;
+        let line: Vec<u8> = self.buffer.drain(..=end).collect();
+
+        let line = line.strip_suffix(b"\n").unwrap_or(&line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        Some(String::from_utf8_lossy(line).into_owned())
+    }
+
+    /// Discard any partially received line.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Header, Property};
+    use bytes::Bytes;
+
+    fn debug_frame(payload: &'static [u8]) -> Frame {
+        Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(
+                Property::Stream(PropertyStream::Debug),
+                Bytes::from_static(payload),
+            ),
+        )
+    }
+
+    #[test]
+    fn reassembles_split_line() {
+        let mut lines = DebugLines::new();
+
+        assert!(lines.push_frame(&debug_frame(b"Framing error 6: [")));
+        assert_eq!(lines.next_line(), None);
+
+        assert!(lines.push_frame(&debug_frame(b"83 02 01 27]\r\nnext")));
+        assert_eq!(
+            lines.next_line(),
+            Some("Framing error 6: [83 02 01 27]".to_string())
+        );
+        assert_eq!(lines.next_line(), None);
+
+        lines.push(b" line\n");
+        assert_eq!(lines.next_line(), Some("next line".to_string()));
+    }
+
+    #[test]
+    fn multiple_lines_in_one_frame() {
+        let mut lines = DebugLines::new();
+        lines.push(b"one\ntwo\n");
+
+        assert_eq!(lines.next_line(), Some("one".to_string()));
+        assert_eq!(lines.next_line(), Some("two".to_string()));
+        assert_eq!(lines.next_line(), None);
+    }
+
+    #[test]
+    fn replaces_invalid_utf8() {
+        let mut lines = DebugLines::new();
+        lines.push(&[b'o', b'k', 0xFF, b'\n']);
+
+        assert_eq!(lines.next_line(), Some("ok\u{FFFD}".to_string()));
+    }
+
+    #[test]
+    fn ignores_other_frames() {
+        let mut lines = DebugLines::new();
+        let frame = Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(
+                Property::Stream(PropertyStream::Log),
+                Bytes::from_static(b"log\n"),
+            ),
+        );
+
+        assert!(!lines.push_frame(&frame));
+        assert_eq!(lines.next_line(), None);
+    }
+}
@@ -2,17 +2,46 @@ use crate::{Error, Frame};
 use bytes::{BufMut, Bytes, BytesMut};
 use crc16::State;
 
+/// Checksum appended to each [`HdlcLiteFrame`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CrcMode {
+    /// CRC-16/X-25, the standard checksum for Spinel over HDLC-lite.
+    #[default]
+    X25,
+
+    /// No checksum, for peers that are configured without one.
+    None,
+}
+
+impl CrcMode {
+    /// Number of checksum bytes at the end of each frame.
+    fn len(&self) -> usize {
+        match self {
+            CrcMode::X25 => 2,
+            CrcMode::None => 0,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct HdlcLiteFrame {
     spinel_frame: Frame,
+    crc_mode: CrcMode,
 }
 
 impl HdlcLiteFrame {
-    const FRAME_DELIMITER_FLAG: u8 = 0x7E;
+    pub(crate) const FRAME_DELIMITER_FLAG: u8 = 0x7E;
     const ESCAPE_BYTE_FLAG: u8 = 0x7D;
     const XON: u8 = 0x11;
     const XOFF: u8 = 0x13;
     const VENDOR_SPECIFIC: u8 = 0xF8;
+    const ESCAPE_XOR: u8 = 0x20;
+
+    /// Smallest possible frame body before the checksum: a header byte.
+    const MIN_FRAME_LEN: usize = 1;
+
+    /// Default limit on the number of encoded bytes between the delimiters of a frame.
+    pub const MAX_FRAME_LEN: usize = 4096;
 
     /// Check if a byte requires escaping.
     fn requires_escape(byte: u8) -> bool {
@@ -43,59 +72,106 @@ impl HdlcLiteFrame {
     /// Returns `None` if no full frame is found.
     #[inline]
     pub fn find_frame(bytes: &Bytes) -> Option<(usize, usize)> {
-        // Find first frame delimiter
-        let mut first_delimiter_pos = Self::find_frame_delimiter(bytes)?;
-        let mut next_delimiter_pos: Option<usize> = None;
-
-        // Search the buffer for the next frame delimiter, returning `None` if no other delimiter is found.
-        for _ in 0..bytes.len() {
-            // Track the position in the original buffer to pass back to the calling function by adding the first
-            // position to the result. This is done because the buffer is being split and the position is relative
-            // to the split.
-            //
-            // Add 1 to the position split to make sure any original frame delimiter is not included in the new
-            // search when calculating the next position.
-            let split_pos = first_delimiter_pos + 1;
-            let next = Self::find_frame_delimiter(&bytes.clone().split_off(split_pos))? + split_pos;
-
-            // Peek ahead to see if the next byte is also frame delimiter
-            if next == split_pos {
-                // Found a repeated frame delimiter, move the delimiter position forward
-                first_delimiter_pos = next;
+        Self::find_frame_in(bytes)
+    }
+
+    /// Find every [`HdlcLiteFrame`] that is surrounded by delimiters in a buffer.
+    ///
+    /// Yields the start and end positions of each complete frame in order. The closing delimiter of one frame may
+    /// also open the next. Bytes after the last complete frame are not yielded.
+    pub fn frames(bytes: &Bytes) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut offset = 0;
+
+        core::iter::from_fn(move || {
+            let (start, end) = Self::find_frame_in(&bytes[offset..])?;
+            let frame = (offset + start, offset + end);
+            offset += end;
+            Some(frame)
+        })
+    }
+
+    /// Find the start and end delimiters of the first frame in a byte slice.
+    ///
+    /// Runs of repeated delimiters are collapsed, the start position is the last delimiter of the run.
+    pub(crate) fn find_frame_in(bytes: &[u8]) -> Option<(usize, usize)> {
+        let mut start: Option<usize> = None;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            if byte != Self::FRAME_DELIMITER_FLAG {
                 continue;
-            } else {
-                // Otherwise, this is the full frame
-                next_delimiter_pos = Some(next);
-                break;
             }
-        }
 
-        // Determine if an end delimiter was found.
-        // Note: `next` shouldn't necessarily end up returning `None` because the loop should always find a delimiter.
-        let next = match next_delimiter_pos {
-            Some(pos) => pos,
-            None => return None,
-        };
+            match start {
+                // A delimiter directly after the start delimiter, move the start forward
+                Some(pos) if pos + 1 == i => start = Some(i),
+                // Otherwise, this is the end of the frame
+                Some(pos) => return Some((pos, i)),
+                None => start = Some(i),
+            }
+        }
 
-        Some((first_delimiter_pos, next))
+        None
     }
 
     /// Create a new [`HdlcLiteFrame`] from a standard Spinel [`Frame`].
     pub fn new(frame: Frame) -> Self {
         Self {
             spinel_frame: frame,
+            crc_mode: CrcMode::default(),
         }
     }
 
+    /// Set the checksum used when encoding the [`HdlcLiteFrame`].
+    pub fn with_crc_mode(self, crc_mode: CrcMode) -> Self {
+        Self { crc_mode, ..self }
+    }
+
+    /// Worst case number of bytes written by [`HdlcLiteFrame::encode`], including both delimiters.
+    ///
+    /// This assumes every byte of the frame and checksum needs escaping, so a buffer of this size never needs to
+    /// grow while encoding.
+    pub fn max_encoded_len(&self) -> usize {
+        2 + 2 * (self.spinel_frame.encoded_len() + self.crc_mode.len())
+    }
+
     /// Encode a [`HdlcLiteFrame`] into a mutable buffer of [`BytesMut`].
-    /// todo: limit?
+    ///
+    /// Returns [`Error::PacketLength`] if the encoded frame is longer than [`HdlcLiteFrame::MAX_FRAME_LEN`].
     pub fn encode(self, buffer: &mut BytesMut) -> Result<(), Error> {
-        // todo: check for escape, new BytesMut first then write to input buffer
+        self.encode_with_max_len(buffer, Self::MAX_FRAME_LEN)
+    }
+
+    /// Encode a [`HdlcLiteFrame`] into a mutable buffer of [`BytesMut`], limiting the frame to `max_len` bytes.
+    ///
+    /// The limit applies to the escaped bytes between the delimiters. Returns [`Error::PacketLength`] with the
+    /// encoded length if it is exceeded, in which case nothing is written to the buffer.
+    pub fn encode_with_max_len(self, buffer: &mut BytesMut, max_len: usize) -> Result<(), Error> {
+        // Serialize the frame and CRC into a scratch buffer first so they can be escaped
+        let mut packet = BytesMut::new();
+        self.spinel_frame.encode(&mut packet)?;
+        if self.crc_mode == CrcMode::X25 {
+            let crc = State::<crc16::X_25>::calculate(&packet);
+            packet.put_u16_le(crc);
+        }
+
+        let escapes = packet
+            .iter()
+            .filter(|&&byte| Self::requires_escape(byte))
+            .count();
+        let len = packet.len() + escapes;
+        if len > max_len {
+            return Err(Error::PacketLength(len));
+        }
 
         buffer.put_u8(Self::FRAME_DELIMITER_FLAG);
-        self.spinel_frame.encode(buffer)?;
-        let crc = State::<crc16::X_25>::calculate(&buffer[1..]);
-        buffer.put_u16_le(crc);
+        for byte in packet.iter() {
+            if Self::requires_escape(*byte) {
+                buffer.put_u8(Self::ESCAPE_BYTE_FLAG);
+                buffer.put_u8(*byte ^ Self::ESCAPE_XOR);
+            } else {
+                buffer.put_u8(*byte);
+            }
+        }
         buffer.put_u8(Self::FRAME_DELIMITER_FLAG);
 
         Ok(())
@@ -107,6 +183,13 @@ impl HdlcLiteFrame {
     /// It is the responsibility of the caller to ensure that the data stream is syncronized and
     /// the frame is complete before calling this function.
     pub fn decode(bytes: &Bytes) -> Result<Self, Error> {
+        Self::decode_with_crc_mode(bytes, CrcMode::X25)
+    }
+
+    /// Decode a [`HdlcLiteFrame`] from a buffer of [`Bytes`] that was sent with the given checksum.
+    ///
+    /// With [`CrcMode::None`] the frame is expected to end directly before the closing delimiter.
+    pub fn decode_with_crc_mode(bytes: &Bytes, crc_mode: CrcMode) -> Result<Self, Error> {
         if let Some(f) = bytes.first() {
             if *f != Self::FRAME_DELIMITER_FLAG {
                 return Err(Error::HdlcStartDelimiter(*f));
@@ -137,28 +220,39 @@ impl HdlcLiteFrame {
             // CRC bytes are also escaped and need correction
             let mut byte_to_write = *byte;
             if need_escape {
-                byte_to_write ^= 0x20;
+                byte_to_write ^= Self::ESCAPE_XOR;
                 need_escape = false;
             }
 
             packet.put_u8(byte_to_write);
         }
 
-        // Split the payload and end of frame data
+        // Guard against empty frames from idle delimiter runs (`7E 7E`) and runt frames
+        // before splitting off the CRC.
         let pkt_len = packet.len();
-        let end_frame_data = packet.split_off(pkt_len - 2);
+        if pkt_len < Self::MIN_FRAME_LEN + crc_mode.len() {
+            return Err(Error::PacketLength(pkt_len));
+        }
 
-        let pkt_crc = u16::from_le_bytes([end_frame_data[0], end_frame_data[1]]);
-        let calculated_crc = State::<crc16::X_25>::calculate(&packet);
+        if crc_mode == CrcMode::X25 {
+            // Split the payload and end of frame data
+            let end_frame_data = packet.split_off(pkt_len - 2);
 
-        if calculated_crc != pkt_crc {
-            return Err(Error::HdlcChecksum(calculated_crc));
+            let pkt_crc = u16::from_le_bytes([end_frame_data[0], end_frame_data[1]]);
+            let calculated_crc = State::<crc16::X_25>::calculate(&packet);
+
+            if calculated_crc != pkt_crc {
+                return Err(Error::HdlcChecksum(calculated_crc));
+            }
         }
 
         let frozen = packet.freeze();
         let spinel_frame = Frame::decode(&frozen)?;
 
-        Ok(Self { spinel_frame })
+        Ok(Self {
+            spinel_frame,
+            crc_mode,
+        })
     }
 
     pub fn into_inner(self) -> Frame {
@@ -217,7 +311,7 @@ mod tests {
         // Generate a some what random vector and make sure it does not contain
         // the frame delimiter
         let mut test_vector: Vec<u8> = (0..512)
-            .map(|_| rng.sample(&range))
+            .map(|_| rng.sample(range))
             .map(|b| if b == 0x7E { b + 1 } else { b })
             .collect();
 
@@ -238,6 +332,111 @@ mod tests {
         assert_eq!(result, Some((2, 7)));
     }
 
+    #[test]
+    fn find_frame_in_large_buffer() {
+        // A long run of delimiters followed by a large frame, the frame starts at the last delimiter of the run
+        let mut bytes = vec![0x7E; 1 << 20];
+        bytes.resize(1 << 21, 0x00);
+        bytes.push(0x7E);
+
+        let result = HdlcLiteFrame::find_frame(&Bytes::from(bytes));
+        assert_eq!(result, Some(((1 << 20) - 1, 1 << 21)));
+    }
+
+    #[test]
+    fn frames_back_to_back() {
+        let mut bytes = TEST_REQ_NOOP_ARRAY.to_vec();
+        bytes.extend_from_slice(&TEST_REQ_NOOP_ARRAY[1..]);
+        bytes.extend_from_slice(&TEST_REQ_NOOP_ARRAY);
+        bytes.extend_from_slice(&[0x7e, 0x81]);
+        let bytes = Bytes::from(bytes);
+
+        let frames: Vec<_> = HdlcLiteFrame::frames(&bytes).collect();
+        assert_eq!(frames, vec![(0, 5), (5, 10), (11, 16)]);
+
+        for (start, end) in frames {
+            let frame = HdlcLiteFrame::decode(&bytes.slice(start..=end)).unwrap();
+            assert_eq!(frame.into_inner().command(), Command::Noop);
+        }
+    }
+
+    #[test]
+    fn frames_empty_without_complete_frame() {
+        let bytes = Bytes::from_static(&TEST_REQ_NOOP_ARRAY[..5]);
+        assert_eq!(HdlcLiteFrame::frames(&bytes).next(), None);
+    }
+
+    #[test]
+    fn encode_rejects_oversized_frame() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueSet(
+                Property::Vendor(0x3C00),
+                Bytes::from(vec![0x00; HdlcLiteFrame::MAX_FRAME_LEN]),
+            ),
+        );
+
+        let mut buffer = BytesMut::new();
+        assert_eq!(
+            HdlcLiteFrame::new(frame.clone()).encode(&mut buffer),
+            Err(Error::PacketLength(HdlcLiteFrame::MAX_FRAME_LEN + 6))
+        );
+        assert!(buffer.is_empty());
+
+        assert!(HdlcLiteFrame::new(frame)
+            .encode_with_max_len(&mut buffer, HdlcLiteFrame::MAX_FRAME_LEN + 6)
+            .is_ok());
+    }
+
+    #[test]
+    fn encode_limit_counts_escaped_bytes() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueSet(Property::Vendor(0x3C00), Bytes::from_static(&[0x7E; 4])),
+        );
+
+        // Header, command, two byte property, four values each escaped to two bytes, and the CRC
+        let mut buffer = BytesMut::new();
+        assert_eq!(
+            HdlcLiteFrame::new(frame).encode_with_max_len(&mut buffer, 13),
+            Err(Error::PacketLength(14))
+        );
+    }
+
+    #[test]
+    fn noop_round_trip_with_crc() {
+        let frame = Frame::new(Header::new(0, 1), Command::Noop);
+
+        let mut buffer = BytesMut::new();
+        HdlcLiteFrame::new(frame.clone())
+            .with_crc_mode(CrcMode::X25)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[..], &TEST_REQ_NOOP_ARRAY);
+
+        let decoded = HdlcLiteFrame::decode_with_crc_mode(&buffer.freeze(), CrcMode::X25).unwrap();
+        assert_eq!(decoded.into_inner(), frame);
+    }
+
+    #[test]
+    fn noop_round_trip_without_crc() {
+        let frame = Frame::new(Header::new(0, 1), Command::Noop);
+
+        let mut buffer = BytesMut::new();
+        HdlcLiteFrame::new(frame.clone())
+            .with_crc_mode(CrcMode::None)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[..], &[0x7e, 0x81, 0x00, 0x7e]);
+
+        let bytes = buffer.freeze();
+        let decoded = HdlcLiteFrame::decode_with_crc_mode(&bytes, CrcMode::None).unwrap();
+        assert_eq!(decoded.into_inner(), frame);
+
+        // Without a checksum the frame is too short to be decoded as a CRC protected frame
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(2)));
+    }
+
     #[test]
     fn errors_on_incorrect_checksum() {
         let mut bytes = BytesMut::from_iter(TEST_REQ_NOOP_ARRAY.iter().cloned());
@@ -262,12 +461,38 @@ mod tests {
         assert_eq!(test, Err(Error::HdlcEndDelimiter(0xF8)));
     }
 
+    #[test]
+    fn decode_empty_frame() {
+        let bytes = Bytes::from_static(&[0x7E, 0x7E]);
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(0)));
+    }
+
+    #[test]
+    fn decode_empty_frame_delimiter_run() {
+        let bytes = Bytes::from_static(&[0x7E, 0x7E, 0x7E]);
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(0)));
+    }
+
+    #[test]
+    fn decode_runt_frames() {
+        // A single content byte can't hold both a header and a CRC
+        let bytes = Bytes::from_static(&[0x7E, 0x81, 0x7E]);
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(1)));
+
+        // Just a CRC, or an escaped byte that only decodes to one byte
+        let bytes = Bytes::from_static(&[0x7E, 0x53, 0x9A, 0x7E]);
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(2)));
+
+        let bytes = Bytes::from_static(&[0x7E, 0x7D, 0x5E, 0x7E]);
+        assert_eq!(HdlcLiteFrame::decode(&bytes), Err(Error::PacketLength(1)));
+    }
+
     #[test]
     fn requires_escape() {
         let escape_bytes = [0x7E, 0x7D, 0x11, 0x13, 0xF8];
         for byte in escape_bytes.iter() {
             let escape = HdlcLiteFrame::requires_escape(*byte);
-            assert_eq!(escape, true);
+            assert!(escape);
         }
     }
 
@@ -290,6 +515,48 @@ mod tests {
         assert_eq!(buffer, Bytes::from_static(&TEST_REQ_NOOP_ARRAY));
     }
 
+    #[test]
+    fn max_encoded_len_covers_noop() {
+        let hdlc_frame = HdlcLiteFrame::new(Frame::new(Header::new(0x00, 0x01), Command::Noop));
+        assert_eq!(hdlc_frame.max_encoded_len(), 10);
+
+        let mut buffer = BytesMut::new();
+        hdlc_frame.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), TEST_REQ_NOOP_ARRAY.len());
+    }
+
+    #[test]
+    fn max_encoded_len_covers_ncp_version() {
+        let hdlc_frame = HdlcLiteFrame::new(Frame::new(
+            Header::new(0x00, 0x01),
+            Command::PropertyValueIs(
+                Property::NcpVersion,
+                Bytes::from_static(TEST_RESP_NCP_VERSION_STR.as_bytes()),
+            ),
+        ));
+        let max_len = hdlc_frame.max_encoded_len();
+
+        let mut buffer = BytesMut::new();
+        hdlc_frame.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), TEST_RESP_NCP_VERSION_ARRAY.len());
+        assert!(buffer.len() <= max_len);
+    }
+
+    #[test]
+    fn max_encoded_len_is_reached_when_all_bytes_escaped() {
+        let hdlc_frame = HdlcLiteFrame::new(Frame::new(
+            Header::new(0x00, 0x01),
+            Command::Vendor(0x3C00, Bytes::from_static(&[0x7E; 8])),
+        ))
+        .with_crc_mode(CrcMode::None);
+        let max_len = hdlc_frame.max_encoded_len();
+
+        let mut buffer = BytesMut::new();
+        hdlc_frame.encode(&mut buffer).unwrap();
+        // The header and command id do not need escaping
+        assert_eq!(buffer.len(), max_len - 3);
+    }
+
     #[test]
     fn decode_noop() {
         let bytes = Bytes::from_static(&TEST_REQ_NOOP_ARRAY);
@@ -337,6 +604,16 @@ mod tests {
         assert_eq!(frame, Ok(expected));
     }
 
+    #[test]
+    fn ncp_version_strips_nul() {
+        let bytes = Bytes::from_static(&TEST_RESP_NCP_VERSION_ARRAY);
+        let frame = HdlcLiteFrame::decode(&bytes).unwrap().into_inner();
+        assert_eq!(
+            frame.ncp_version(),
+            Some(Ok(TEST_RESP_NCP_VERSION_STR.trim_end_matches('\0')))
+        );
+    }
+
     #[test]
     fn encode_ncp_version_property_is() {
         let header = Header::new(0x00, 0x01);
@@ -351,12 +628,46 @@ mod tests {
         assert_eq!(buffer, Bytes::from_static(&TEST_RESP_NCP_VERSION_ARRAY));
     }
 
+    #[test]
+    fn encode_escapes_reserved_bytes() {
+        let frame = Frame::new(
+            Header::new(0x00, 0x01),
+            Command::PropertyValueIs(
+                Property::NcpVersion,
+                Bytes::from_static(&[0x7E, 0x41, 0x7D]),
+            ),
+        );
+
+        let mut buffer = BytesMut::new();
+        HdlcLiteFrame::new(frame.clone())
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            &buffer[..9],
+            &[0x7E, 0x81, 0x06, 0x02, 0x7D, 0x5E, 0x41, 0x7D, 0x5D]
+        );
+
+        let decoded = HdlcLiteFrame::decode(&buffer.freeze());
+        assert_eq!(decoded, Ok(HdlcLiteFrame::new(frame)));
+    }
+
     #[test]
     fn decode_stream() {
+        use crate::codec::NetStreamFrame;
+        use crate::PropertyStream;
+
         let bytes = Bytes::from_static(&TEST_HDLC_DECODE_STREAM);
-        println!("bytes: {:02x?}", &bytes[..]);
-        let frame = HdlcLiteFrame::decode(&bytes);
-        assert!(frame.is_ok());
-        // todo: assert frame is stream
+        let frame = HdlcLiteFrame::decode(&bytes).unwrap().into_inner();
+
+        let Some((PropertyStream::NetInsecure, payload)) = frame.stream_payload() else {
+            panic!("expected an insecure net stream frame");
+        };
+
+        // A single 84 byte IPv6 packet without any trailing metadata
+        let stream = NetStreamFrame::decode(&payload).unwrap();
+        assert_eq!(stream.data.len(), 84);
+        assert_eq!(&stream.data[..2], &[0x60, 0x00]);
+        assert_eq!(&stream.data[82..], &[0xAE, 0x3A]);
+        assert_eq!(stream.metadata, None);
     }
 }
@@ -1,5 +1,7 @@
 mod hdlc;
+mod spi;
 mod spinel;
 
 pub use hdlc::*;
+pub use spi::*;
 pub use spinel::*;
@@ -0,0 +1,279 @@
+use crate::codec::{Decode, Encode};
+use crate::{Error, Frame};
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// Header sent at the start of every Spinel SPI transaction.
+///
+/// Spinel over SPI does not use HDLC-lite. Instead, each transfer begins with this 5 byte header followed by
+/// `data_len` bytes of the spinel frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpiHeader {
+    /// The sender has reset since the last transaction.
+    pub reset: bool,
+
+    /// A CRC follows the frame data.
+    ///
+    /// CRCs are not supported by [`SpiFrame`], which rejects frames with this flag set.
+    pub crc: bool,
+
+    /// The CRC of the previously received frame failed.
+    pub ccf: bool,
+
+    /// The number of bytes the sender is able to receive in this transaction.
+    pub accept_len: u16,
+
+    /// The number of frame bytes following the header.
+    pub data_len: u16,
+}
+
+impl SpiHeader {
+    /// Length of the header in bytes.
+    pub const LEN: usize = 5;
+
+    const FLAG_RESET: u8 = 0b1000_0000;
+    const FLAG_CRC: u8 = 0b0100_0000;
+    const FLAG_CCF: u8 = 0b0010_0000;
+    const FLAG_PATTERN_MASK: u8 = 0b0000_0011;
+    const FLAG_PATTERN: u8 = 0b0000_0010;
+
+    /// Create a new [`SpiHeader`] with no flags set.
+    pub fn new(accept_len: u16, data_len: u16) -> Self {
+        Self {
+            accept_len,
+            data_len,
+            ..Self::default()
+        }
+    }
+
+    /// The flag byte of the header.
+    fn flag(&self) -> u8 {
+        let mut flag = Self::FLAG_PATTERN;
+
+        if self.reset {
+            flag |= Self::FLAG_RESET;
+        }
+        if self.crc {
+            flag |= Self::FLAG_CRC;
+        }
+        if self.ccf {
+            flag |= Self::FLAG_CCF;
+        }
+
+        flag
+    }
+}
+
+impl<'a> Decode<'a> for SpiHeader {
+    fn decode(bytes: &'a [u8]) -> Result<(Self, usize), Error> {
+        if bytes.len() < Self::LEN {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
+        let flag = bytes[0];
+        if flag & Self::FLAG_PATTERN_MASK != Self::FLAG_PATTERN {
+            return Err(Error::SpiHeader(flag));
+        }
+
+        let header = Self {
+            reset: flag & Self::FLAG_RESET != 0,
+            crc: flag & Self::FLAG_CRC != 0,
+            ccf: flag & Self::FLAG_CCF != 0,
+            accept_len: u16::decode(&bytes[1..])?.0,
+            data_len: u16::decode(&bytes[3..])?.0,
+        };
+
+        Ok((header, Self::LEN))
+    }
+}
+
+impl Encode for SpiHeader {
+    fn encode(&self, buffer: &mut BytesMut) -> usize {
+        buffer.put_u8(self.flag());
+        self.accept_len.encode(buffer);
+        self.data_len.encode(buffer);
+        Self::LEN
+    }
+}
+
+/// A Spinel [`Frame`] wrapped with an [`SpiHeader`] for transfer over SPI.
+///
+/// Frames are sent without a CRC. Decoding a transaction with the [`SpiHeader::crc`] flag set returns
+/// [`Error::SpiHeader`] instead of passing the CRC bytes on as part of the frame.
+#[derive(Debug, PartialEq)]
+pub struct SpiFrame {
+    header: SpiHeader,
+    spinel_frame: Option<Frame>,
+}
+
+impl SpiFrame {
+    /// Create a new [`SpiFrame`] from a standard Spinel [`Frame`].
+    ///
+    /// `accept_len` advertises how many bytes can be received from the peer in the same transaction.
+    pub fn new(frame: Frame, accept_len: u16) -> Self {
+        Self {
+            header: SpiHeader::new(accept_len, 0),
+            spinel_frame: Some(frame),
+        }
+    }
+
+    /// Set the [`SpiHeader::reset`] flag, telling the peer that the sender has reset.
+    pub fn with_reset(self, reset: bool) -> Self {
+        Self {
+            header: SpiHeader {
+                reset,
+                ..self.header
+            },
+            ..self
+        }
+    }
+
+    /// Set the [`SpiHeader::ccf`] flag, telling the peer that its previous frame failed the CRC check.
+    pub fn with_ccf(self, ccf: bool) -> Self {
+        Self {
+            header: SpiHeader { ccf, ..self.header },
+            ..self
+        }
+    }
+
+    /// Encode a [`SpiFrame`] into a mutable buffer of [`BytesMut`].
+    ///
+    /// The data length in the header is set from the encoded frame.
+    pub fn encode(self, buffer: &mut BytesMut) -> Result<(), Error> {
+        let mut packet = BytesMut::new();
+        if let Some(frame) = self.spinel_frame {
+            frame.encode(&mut packet)?;
+        }
+
+        let data_len =
+            u16::try_from(packet.len()).map_err(|_| Error::PacketLength(packet.len()))?;
+        let header = SpiHeader {
+            data_len,
+            ..self.header
+        };
+
+        header.encode(buffer);
+        buffer.put_slice(&packet);
+
+        Ok(())
+    }
+
+    /// Decode a [`SpiFrame`] from the bytes received in an SPI transaction.
+    ///
+    /// Any bytes past the data length in the header are ignored. A header with a data length of zero carries no
+    /// frame. Returns [`Error::SpiHeader`] if the [`SpiHeader::crc`] flag is set, as CRCs are not supported.
+    pub fn decode(bytes: &Bytes) -> Result<Self, Error> {
+        let (header, header_len) = SpiHeader::decode(bytes)?;
+        if header.crc {
+            return Err(Error::SpiHeader(header.flag()));
+        }
+        let end = header_len + header.data_len as usize;
+
+        if bytes.len() < end {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
+        let spinel_frame = match header.data_len {
+            0 => None,
+            _ => Some(Frame::decode(&bytes.slice(header_len..end))?),
+        };
+
+        Ok(Self {
+            header,
+            spinel_frame,
+        })
+    }
+
+    /// Retrieve a copy of the [`SpiHeader`] from the [`SpiFrame`].
+    pub fn header(&self) -> SpiHeader {
+        self.header
+    }
+
+    /// Consume the [`SpiFrame`] and return the Spinel [`Frame`], or `None` if the transaction carried no frame.
+    pub fn into_inner(self) -> Option<Frame> {
+        self.spinel_frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Header};
+
+    const TEST_SPI_NOOP: [u8; 7] = [0x02, 0x00, 0x01, 0x02, 0x00, 0x81, 0x00];
+
+    #[test]
+    fn header_round_trip() {
+        let header = SpiHeader {
+            reset: true,
+            crc: false,
+            ccf: true,
+            accept_len: 0x1234,
+            data_len: 0x0010,
+        };
+
+        let mut buffer = BytesMut::new();
+        assert_eq!(header.encode(&mut buffer), SpiHeader::LEN);
+        assert_eq!(&buffer[..], &[0xA2, 0x34, 0x12, 0x10, 0x00]);
+        assert_eq!(SpiHeader::decode(&buffer), Ok((header, SpiHeader::LEN)));
+    }
+
+    #[test]
+    fn header_invalid_pattern() {
+        let bytes = [0x01, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(SpiHeader::decode(&bytes), Err(Error::SpiHeader(0x01)));
+    }
+
+    #[test]
+    fn encode_noop() {
+        let frame = Frame::new(Header::new(0x00, 0x01), Command::Noop);
+        let mut buffer = BytesMut::new();
+        SpiFrame::new(frame, 256).encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..], &TEST_SPI_NOOP);
+    }
+
+    #[test]
+    fn decode_noop_ignores_trailing_bytes() {
+        let mut bytes = BytesMut::from(&TEST_SPI_NOOP[..]);
+        bytes.put_slice(&[0xFF; 4]);
+
+        let frame = SpiFrame::decode(&bytes.freeze()).unwrap();
+        assert_eq!(frame.header(), SpiHeader::new(256, 2));
+        assert_eq!(
+            frame.into_inner(),
+            Some(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+        );
+    }
+
+    #[test]
+    fn encode_header_flags() {
+        let frame = Frame::new(Header::new(0x00, 0x01), Command::Noop);
+        let mut buffer = BytesMut::new();
+        SpiFrame::new(frame, 256)
+            .with_reset(true)
+            .with_ccf(true)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer[0], 0xA2);
+        assert_eq!(&buffer[1..], &TEST_SPI_NOOP[1..]);
+    }
+
+    #[test]
+    fn decode_rejects_crc() {
+        // A noop followed by a 2 byte CRC
+        let bytes = Bytes::from_static(&[0x42, 0x00, 0x01, 0x04, 0x00, 0x81, 0x00, 0x12, 0x34]);
+        assert_eq!(SpiFrame::decode(&bytes), Err(Error::SpiHeader(0x42)));
+    }
+
+    #[test]
+    fn decode_empty_transaction() {
+        let bytes = Bytes::from_static(&[0x02, 0x00, 0x01, 0x00, 0x00]);
+        let frame = SpiFrame::decode(&bytes).unwrap();
+        assert_eq!(frame.into_inner(), None);
+    }
+
+    #[test]
+    fn decode_truncated_data() {
+        let bytes = Bytes::copy_from_slice(&TEST_SPI_NOOP[..6]);
+        assert_eq!(SpiFrame::decode(&bytes), Err(Error::PacketLength(6)));
+    }
+}
@@ -1,5 +1,54 @@
-use crate::{Command, Error, Property, Status};
+use crate::{
+    codec::{
+        CommandRef, Decode, EnergyScanResult, Eui64, HostPowerState, InterfaceType, McuPowerState,
+        PackedU32, ProtocolVersion, ScanState,
+    },
+    Command, Error, Property, PropertyStream, Status,
+};
 use bytes::{BufMut, Bytes, BytesMut};
+use core::fmt;
+
+/// The Instance Identifier (IID) of a [`Header`], used to address one of several stacks on a device.
+///
+/// The IID field of the header is 2 bits wide, so valid identifiers are `0` to `3`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InstanceId(u8);
+
+impl InstanceId {
+    /// The largest valid Instance Identifier.
+    pub const MAX: u8 = 3;
+
+    /// Create a new [`InstanceId`], returning [`Error::InstanceId`] if it is greater than [`InstanceId::MAX`].
+    pub fn new(iid: u8) -> Result<Self, Error> {
+        if iid > Self::MAX {
+            return Err(Error::InstanceId(iid));
+        }
+
+        Ok(Self(iid))
+    }
+
+    /// Get the Instance Identifier as a `u8`.
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for InstanceId {
+    /// Convert a `u8` into an [`InstanceId`].
+    ///
+    /// Values greater than [`InstanceId::MAX`] panic in debug builds and are truncated to 2 bits otherwise. Use
+    /// [`InstanceId::new`] to check the value instead.
+    fn from(iid: u8) -> Self {
+        debug_assert!(iid <= Self::MAX, "instance identifier out of range: {iid}");
+        Self(iid & Self::MAX)
+    }
+}
+
+impl From<InstanceId> for u8 {
+    fn from(iid: InstanceId) -> Self {
+        iid.0
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Header {
@@ -15,16 +64,59 @@ impl Header {
     const HEADER_IID_MASK: u8 = 0b0011_0000;
     const HEADER_IID_SHIFT: u32 = 4;
     const HEADER_TID_MASK: u8 = 0b0000_1111;
+    const HEADER_TID_MAX: u8 = Self::HEADER_TID_MASK;
 
     /// Create a new [`Header`] with the a Instance Identifier (IID) and Transaction Identifier (TID).
-    pub fn new(iid: u8, tid: u8) -> Self {
+    ///
+    /// A plain `u8` IID is converted with `From<u8>`, which does not check the range in release builds. Use
+    /// [`InstanceId::new`] to check it first. The TID is handled the same way: values greater than 15 panic in debug
+    /// builds and are truncated to 4 bits otherwise.
+    pub fn new(iid: impl Into<InstanceId>, tid: u8) -> Self {
+        debug_assert!(
+            tid <= Self::HEADER_TID_MAX,
+            "transaction identifier out of range: {tid}"
+        );
+
         Self {
             flag: Self::HEADER_FLAG,
-            iid,
-            tid,
+            iid: iid.into().get(),
+            tid: tid & Self::HEADER_TID_MASK,
+        }
+    }
+
+    /// Create a new [`Header`], checking that the IID and TID fit in their fields.
+    ///
+    /// Returns [`Error::InstanceId`] if the IID is greater than 3, or [`Error::TransactionId`] if the TID is greater
+    /// than 15.
+    pub(crate) fn checked(iid: u8, tid: u8) -> Result<Self, Error> {
+        let iid = InstanceId::new(iid)?;
+
+        if tid > Self::HEADER_TID_MAX {
+            return Err(Error::TransactionId(tid));
+        }
+
+        Ok(Self::new(iid, tid))
+    }
+
+    /// Create a new [`Header`] with an explicit flag, Instance Identifier (IID) and Transaction Identifier (TID).
+    ///
+    /// Unlike [`Header::new`], the flag is not required to be `0b10`. This allows building headers that a device
+    /// should reject, for example to test its header validation. Only the lower two bits of the flag and the lower
+    /// four bits of the TID are used, so neither can spill into the other fields. The IID is converted the same way
+    /// as in [`Header::new`].
+    pub fn with_flag(flag: u8, iid: impl Into<InstanceId>, tid: u8) -> Self {
+        Self {
+            flag: flag & (Self::HEADER_FLAG_MASK >> Self::HEADER_FLAG_SHIFT),
+            iid: iid.into().get(),
+            tid: tid & Self::HEADER_TID_MASK,
         }
     }
 
+    /// Get the flag from the header.
+    pub fn flag(&self) -> u8 {
+        self.flag
+    }
+
     /// Get the Instance Identifier (IID) from the header.
     pub fn iid(&self) -> u8 {
         self.iid
@@ -34,13 +126,16 @@ impl Header {
     pub fn tid(&self) -> u8 {
         self.tid
     }
+
+    /// Get the header packed into the byte sent on the wire.
+    pub fn raw(&self) -> u8 {
+        (self.flag << Self::HEADER_FLAG_SHIFT) | (self.iid << Self::HEADER_IID_SHIFT) | self.tid
+    }
 }
 
 impl From<Header> for u8 {
     fn from(header: Header) -> Self {
-        (header.flag << Header::HEADER_FLAG_SHIFT)
-            | (header.iid << Header::HEADER_IID_SHIFT)
-            | header.tid
+        header.raw()
     }
 }
 
@@ -48,8 +143,8 @@ impl TryFrom<u8> for Header {
     type Error = Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        let flag = (value & Self::HEADER_FLAG_MASK).rotate_right(Self::HEADER_FLAG_SHIFT);
-        let iid = (value & Self::HEADER_IID_MASK).rotate_right(Self::HEADER_IID_SHIFT);
+        let flag = (value & Self::HEADER_FLAG_MASK) >> Self::HEADER_FLAG_SHIFT;
+        let iid = (value & Self::HEADER_IID_MASK) >> Self::HEADER_IID_SHIFT;
         let tid = value & Self::HEADER_TID_MASK;
 
         if flag != Self::HEADER_FLAG {
@@ -72,6 +167,26 @@ impl Frame {
         Self { header, command }
     }
 
+    /// Create a [`Frame`] that gets the value of a [`Property`].
+    ///
+    /// Returns an error if the IID or TID does not fit in the [`Header`].
+    pub fn get(iid: u8, tid: u8, prop: Property) -> Result<Self, Error> {
+        Ok(Self::new(
+            Header::checked(iid, tid)?,
+            Command::PropertyValueGet(prop),
+        ))
+    }
+
+    /// Create a [`Frame`] that sets the value of a [`Property`].
+    ///
+    /// Returns an error if the IID or TID does not fit in the [`Header`].
+    pub fn set(iid: u8, tid: u8, prop: Property, value: Bytes) -> Result<Self, Error> {
+        Ok(Self::new(
+            Header::checked(iid, tid)?,
+            Command::PropertyValueSet(prop, value),
+        ))
+    }
+
     /// Encode the [`Frame`] and write it to a buffer.
     pub fn encode(self, buffer: &mut BytesMut) -> Result<(), Error> {
         let header_byte = u8::from(self.header);
@@ -85,16 +200,38 @@ impl Frame {
 
     /// Decode a [`Frame`] from a buffer.
     pub fn decode(buffer: &Bytes) -> Result<Self, Error> {
+        Self::decode_ref(buffer).map(Frame::from)
+    }
+
+    /// Decode a [`FrameRef`] that borrows its payload from the buffer, without allocating.
+    pub fn decode_ref(buffer: &[u8]) -> Result<FrameRef<'_>, Error> {
         if buffer.len() < 2 {
             return Err(Error::PacketLength(buffer.len()));
         }
 
-        Ok(Frame {
+        Ok(FrameRef {
             header: Header::try_from(buffer[0])?,
-            command: Command::decode(&buffer.clone().split_off(1))?,
+            command: CommandRef::decode(&buffer[1..])?,
         })
     }
 
+    /// Number of bytes written by [`Frame::encode`]: the header, packed command id and payload.
+    pub fn encoded_len(&self) -> usize {
+        1 + self.command.packed_len() + self.command.payload_len()
+    }
+
+    /// Encode a copy of the [`Frame`] into the bytes that are sent on the wire, without HDLC-lite framing.
+    pub fn to_wire_bytes(&self) -> Result<Bytes, Error> {
+        let mut buffer = BytesMut::new();
+        self.clone().encode(&mut buffer)?;
+        Ok(buffer.freeze())
+    }
+
+    /// Encode a copy of the [`Frame`] for display as space separated hex bytes, for example `81 00`.
+    pub fn hexdump(&self) -> Result<HexDump, Error> {
+        self.to_wire_bytes().map(HexDump)
+    }
+
     /// Retrieve a copy of the [`Header`] from the [`Frame`].
     pub fn header(&self) -> Header {
         self.header.clone()
@@ -105,21 +242,193 @@ impl Frame {
         self.command.clone()
     }
 
+    /// Replace the [`Header`] of the [`Frame`], keeping the [`Command`].
+    ///
+    /// This is useful when relaying a frame to another host or device under a new IID or TID.
+    pub fn with_header(self, header: Header) -> Self {
+        Self { header, ..self }
+    }
+
+    /// Transform the [`Command`] of the [`Frame`], keeping the [`Header`].
+    pub fn map_command(self, f: impl FnOnce(Command) -> Command) -> Self {
+        Self {
+            header: self.header,
+            command: f(self.command),
+        }
+    }
+
+    /// Check if the [`Frame`] was sent unsolicited by the device, rather than in response to a request.
+    ///
+    /// Unsolicited frames use the reserved Transaction Identifier (TID) of `0`.
+    pub fn is_broadcast(&self) -> bool {
+        self.header.tid == 0
+    }
+
+    /// Extract the stream and payload if the [`Frame`] is a [`Command::PropertyValueIs`] for a stream property.
+    pub fn stream_payload(&self) -> Option<(PropertyStream, Bytes)> {
+        match &self.command {
+            Command::PropertyValueIs(Property::Stream(stream), payload) => {
+                Some((stream.clone(), payload.clone()))
+            }
+            _ => None,
+        }
+    }
+
     /// Check the [`Frame`] to see if it has a [`Command::PropertyValueIs`] with a [`Property::LastStatus`].
     ///
-    /// Returns the [`Status`] if it exists, otherwise `None`.
-    pub fn last_status(&self) -> Option<Status> {
+    /// Returns the decoded [`Status`] if it exists, otherwise `None`. The status is a packed `uint32`, an empty
+    /// payload is returned as [`Error::PacketLength`] and an unterminated one as [`Error::PackedU32ByteCount`].
+    pub fn last_status(&self) -> Option<Result<Status, Error>> {
         match &self.command {
-            Command::PropertyValueIs(prop, value) => {
-                if *prop == Property::LastStatus {
-                    Some(Status::try_from(value[0]).unwrap())
-                } else {
-                    None
-                }
+            Command::PropertyValueIs(Property::LastStatus, value) if value.is_empty() => {
+                Some(Err(Error::PacketLength(0)))
+            }
+            Command::PropertyValueIs(Property::LastStatus, value) => {
+                Some(PackedU32::decode_unbounded(value).map(|(code, _)| Status::from(code)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check the [`Frame`] to see if it has a [`Command::PropertyValueIs`] with a [`Property::NcpVersion`].
+    ///
+    /// Returns the version string without its NUL terminator, otherwise `None`. A value that is not valid UTF-8 or
+    /// is missing the terminator is returned as an error.
+    pub fn ncp_version(&self) -> Option<Result<&str, Error>> {
+        match &self.command {
+            Command::PropertyValueIs(Property::NcpVersion, value) => {
+                Some(<&str>::decode(value).map(|(version, _)| version))
             }
             _ => None,
         }
     }
+
+    /// Check that the value carried by the [`Frame`] has the format expected for its [`Property`].
+    ///
+    /// [`Frame::decode`] only checks the structure of the frame, so a corrupt value that happens to pass the CRC is
+    /// still accepted. This decodes the value of a [`Command::PropertyValueIs`] or [`Command::PropertyValueSet`]
+    /// for properties with a fixed format and checks that no bytes are left over. Returns
+    /// [`Error::PropertyValue`] if it does not match. Lists, streams and vendor properties are not checked.
+    pub fn validate(&self) -> Result<(), Error> {
+        let (Command::PropertyValueIs(prop, value) | Command::PropertyValueSet(prop, value)) =
+            &self.command
+        else {
+            return Ok(());
+        };
+
+        let len = match prop {
+            Property::LastStatus => PackedU32::decode_unbounded(value).map(|(_, len)| len),
+            Property::ProtocolVersion => decoded_len::<ProtocolVersion>(value),
+            Property::NcpVersion | Property::NetNetworkName => decoded_len::<&str>(value),
+            Property::InterfaceType => decoded_len::<InterfaceType>(value),
+            Property::HostPowerState => decoded_len::<HostPowerState>(value),
+            Property::McuPowerState => decoded_len::<McuPowerState>(value),
+            Property::PowerState => McuPowerState::decode_legacy(value).map(|(_, len)| len),
+            Property::PhyChannel | Property::NetRole | Property::CntrReset => {
+                decoded_len::<u8>(value)
+            }
+            Property::PhyTxPower | Property::PhyRssi => decoded_len::<i8>(value),
+            Property::MacScanState => decoded_len::<ScanState>(value),
+            Property::MacEnergyScanResult => decoded_len::<EnergyScanResult>(value),
+            Property::MacScanPeriod | Property::Mac15_4SAddr | Property::Mac15_4PanId => {
+                decoded_len::<u16>(value)
+            }
+            Property::HardwareAddress | Property::Mac15_4LAddr | Property::Mac15_4ExtendedAddr => {
+                decoded_len::<Eui64>(value)
+            }
+            Property::NetIfUp | Property::NetStackUp => decoded_len::<bool>(value),
+            Property::MacDataPollPeriod
+            | Property::NetKeySequenceCounter
+            | Property::NetPartitionId
+            | Property::CntrTxPktTotal
+            | Property::CntrTxPktAcked
+            | Property::CntrTxPktRetry
+            | Property::CntrTxErrCca
+            | Property::CntrTxErrAbort
+            | Property::CntrRxPktTotal
+            | Property::CntrRxErrBadFcs
+            | Property::CntrRxErrOther
+            | Property::CntrRxPktDup => decoded_len::<u32>(value),
+            _ => return Ok(()),
+        };
+
+        match len {
+            Ok(len) if len == value.len() => Ok(()),
+            _ => Err(Error::PropertyValue(prop.clone())),
+        }
+    }
+}
+
+/// Decode a `T` from the start of the value and return the number of bytes it used.
+fn decoded_len<'a, T: Decode<'a>>(value: &'a [u8]) -> Result<usize, Error> {
+    T::decode(value).map(|(_, len)| len)
+}
+
+/// A [`Frame`] that borrows its payload from the buffer it was decoded from, see [`Frame::decode_ref`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameRef<'a> {
+    pub(crate) header: Header,
+    pub(crate) command: CommandRef<'a>,
+}
+
+impl<'a> FrameRef<'a> {
+    /// Get the [`Header`] of the [`FrameRef`].
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Get the [`CommandRef`] of the [`FrameRef`].
+    pub fn command(&self) -> &CommandRef<'a> {
+        &self.command
+    }
+}
+
+impl From<FrameRef<'_>> for Frame {
+    fn from(frame: FrameRef<'_>) -> Self {
+        Frame {
+            header: frame.header,
+            command: Command::from(frame.command),
+        }
+    }
+}
+
+/// Encoded bytes of a [`Frame`] that display as space separated hex, see [`Frame::hexdump`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexDump(Bytes);
+
+impl HexDump {
+    /// Parse hex bytes in the format produced by [`Frame::hexdump`], for example `81 00`.
+    ///
+    /// Whitespace between bytes is optional. Returns `None` for an odd number of digits or a character that is
+    /// not a hex digit.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut bytes = BytesMut::with_capacity(s.len() / 2);
+        let mut digits = s.chars().filter(|c| !c.is_ascii_whitespace());
+
+        while let Some(high) = digits.next() {
+            let low = digits.next()?;
+            bytes.put_u8((high.to_digit(16)? << 4 | low.to_digit(16)?) as u8);
+        }
+
+        Some(Self(bytes.freeze()))
+    }
+
+    /// Consume the [`HexDump`] and return the bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl fmt::Display for HexDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +448,43 @@ mod tests {
         assert_eq!(header, HEADER_IID_01_TID_02);
     }
 
+    #[test]
+    fn instance_id_boundary() {
+        assert_eq!(InstanceId::new(0).map(|iid| iid.get()), Ok(0));
+        assert_eq!(InstanceId::new(3).map(|iid| iid.get()), Ok(3));
+        assert_eq!(InstanceId::new(4), Err(Error::InstanceId(4)));
+        assert_eq!(InstanceId::new(0xFF), Err(Error::InstanceId(0xFF)));
+    }
+
+    #[test]
+    fn header_new_with_instance_id() {
+        let header = Header::new(InstanceId::new(3).unwrap(), 0x2);
+        assert_eq!(header.iid(), 3);
+        assert_eq!(u8::from(header), 0b1011_0010);
+    }
+
+    #[test]
+    #[should_panic(expected = "instance identifier out of range")]
+    #[cfg(debug_assertions)]
+    fn instance_id_from_u8_out_of_range() {
+        let _ = InstanceId::from(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction identifier out of range")]
+    #[cfg(debug_assertions)]
+    fn header_new_tid_out_of_range() {
+        let _ = Header::new(0, 16);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn header_new_masks_tid() {
+        let header = Header::new(0, 0x12);
+        assert_eq!(header.tid(), 0x2);
+        assert_eq!(header.raw(), 0b1000_0010);
+    }
+
     #[test]
     fn header_try_from_u8() {
         let header_byte = HEADER_IID_01_IID_02_BYTE;
@@ -152,6 +498,53 @@ mod tests {
         assert_eq!(header_byte, HEADER_IID_01_IID_02_BYTE);
     }
 
+    #[test]
+    fn header_raw() {
+        assert_eq!(HEADER_IID_01_TID_02.raw(), HEADER_IID_01_IID_02_BYTE);
+        assert_eq!(Header::with_flag(0b01, 0x3, 0xF).raw(), 0b0111_1111);
+    }
+
+    #[test]
+    fn header_round_trips_every_valid_header() {
+        for iid in 0..=InstanceId::MAX {
+            for tid in 0..=Header::HEADER_TID_MAX {
+                let header = Header::new(iid, tid);
+                let decoded = Header::try_from(header.raw()).unwrap();
+
+                assert_eq!(decoded, header);
+                assert_eq!(
+                    (decoded.flag(), decoded.iid(), decoded.tid()),
+                    (0b10, iid, tid)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn header_round_trips_random_bytes() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..1000 {
+            let byte: u8 = rng.gen();
+
+            match Header::try_from(byte) {
+                Ok(header) => {
+                    assert_eq!(byte & Header::HEADER_FLAG_MASK, 0b1000_0000);
+                    assert_eq!(header.raw(), byte);
+                }
+                Err(e) => {
+                    assert_ne!(byte & Header::HEADER_FLAG_MASK, 0b1000_0000);
+                    assert_eq!(e, Error::Header(byte));
+                }
+            }
+
+            // Headers built with any flag pack into the same byte
+            let header = Header::with_flag(byte >> 6, (byte >> 4) & 0b11, byte & 0x0F);
+            assert_eq!(header.raw(), byte);
+        }
+    }
+
     #[test]
     fn header_missing_flag() {
         let header_byte = 0b0001_0010;
@@ -159,10 +552,363 @@ mod tests {
         assert_eq!(header, Err(Error::Header(header_byte)));
     }
 
+    #[test]
+    fn header_flag() {
+        assert_eq!(HEADER_IID_01_TID_02.flag(), 0b10);
+        assert_eq!(Header::new(0x1, 0x2).flag(), 0b10);
+    }
+
+    #[test]
+    fn header_with_flag() {
+        let header = Header::with_flag(0b10, 0x1, 0x2);
+        assert_eq!(header, HEADER_IID_01_TID_02);
+
+        let header = Header::with_flag(0b01, 0x1, 0x2);
+        assert_eq!(header.flag(), 0b01);
+
+        let header_byte = u8::from(header);
+        assert_eq!(header_byte, 0b0101_0010);
+        assert_eq!(
+            Header::try_from(header_byte),
+            Err(Error::Header(header_byte))
+        );
+    }
+
+    #[test]
+    fn header_with_flag_masks_extra_bits() {
+        let header = Header::with_flag(0xFF, 0x0, 0x1);
+        assert_eq!(header.flag(), 0b11);
+        assert_eq!(u8::from(header), 0b1100_0001);
+
+        let header = Header::with_flag(0b10, 0x1, 0x12);
+        assert_eq!(header.tid(), 0x2);
+        assert_eq!(u8::from(header), 0b1001_0010);
+    }
+
+    #[test]
+    fn header_with_flag_round_trips_fields() {
+        for flag in 0..=0b11 {
+            for iid in 0..=InstanceId::MAX {
+                for tid in 0..=Header::HEADER_TID_MAX {
+                    let raw = Header::with_flag(flag, iid, tid).raw();
+                    let header = Header::with_flag(raw >> 6, (raw >> 4) & 0b11, raw & 0x0F);
+
+                    assert_eq!(raw, (flag << 6) | (iid << 4) | tid);
+                    assert_eq!(
+                        (header.flag(), header.iid(), header.tid()),
+                        (flag, iid, tid)
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn frame_decode_at_least_two_bytes() {
         let buffer = Bytes::from_static(&[0x01]);
         let frame = Frame::decode(&buffer);
         assert_eq!(frame, Err(Error::PacketLength(1)));
     }
+
+    #[test]
+    fn frame_get_and_set() {
+        let frame = Frame::get(3, 15, Property::NcpVersion).unwrap();
+        assert_eq!(
+            frame,
+            Frame::new(
+                Header::new(3, 15),
+                Command::PropertyValueGet(Property::NcpVersion)
+            )
+        );
+
+        let frame = Frame::set(0, 1, Property::NetIfUp, Bytes::from_static(&[0x01])).unwrap();
+        assert_eq!(
+            frame.to_wire_bytes(),
+            Ok(Bytes::from_static(&[0x81, 0x03, 0x41, 0x01]))
+        );
+    }
+
+    #[test]
+    fn frame_get_and_set_out_of_range() {
+        assert_eq!(
+            Frame::get(0, 16, Property::NcpVersion),
+            Err(Error::TransactionId(16))
+        );
+        assert_eq!(
+            Frame::get(4, 0, Property::NcpVersion),
+            Err(Error::InstanceId(4))
+        );
+        assert_eq!(
+            Frame::set(0, 0xFF, Property::NetIfUp, Bytes::new()),
+            Err(Error::TransactionId(0xFF))
+        );
+    }
+
+    #[test]
+    fn frame_decode_ref_noop() {
+        let buffer = [0x81, 0x00];
+        let frame = Frame::decode_ref(&buffer).unwrap();
+        assert_eq!(frame.header(), &Header::new(0, 1));
+        assert_eq!(frame.command(), &CommandRef::Noop);
+        assert_eq!(
+            Frame::from(frame),
+            Frame::new(Header::new(0, 1), Command::Noop)
+        );
+    }
+
+    #[test]
+    fn frame_decode_ref_ncp_version() {
+        let mut buffer = vec![0x81, 0x06, 0x02];
+        buffer.extend_from_slice(b"OPENTHREAD/1.0\0");
+
+        let frame = Frame::decode_ref(&buffer).unwrap();
+        let CommandRef::PropertyValueIs(Property::NcpVersion, value) = frame.command() else {
+            panic!("failed to decode NCP version");
+        };
+
+        // The value borrows from the buffer rather than being copied
+        assert_eq!(*value, b"OPENTHREAD/1.0\0");
+        assert!(core::ptr::eq(value.as_ptr(), buffer[3..].as_ptr()));
+    }
+
+    #[test]
+    fn frame_decode_ref_errors() {
+        assert_eq!(Frame::decode_ref(&[0x81]), Err(Error::PacketLength(1)));
+        assert_eq!(Frame::decode_ref(&[0x01, 0x00]), Err(Error::Header(0x01)));
+    }
+
+    #[test]
+    fn frame_to_wire_bytes() {
+        let frame = Frame::new(
+            Header::new(0, 3),
+            Command::PropertyValueGet(Property::ProtocolVersion),
+        );
+        assert_eq!(
+            frame.to_wire_bytes(),
+            Ok(Bytes::from_static(&[0x83, 0x02, 0x01]))
+        );
+    }
+
+    #[test]
+    fn frame_encoded_len() {
+        for frame in [
+            Frame::new(Header::new(0, 1), Command::Noop),
+            Frame::get(0, 2, Property::Mac15_4ExtendedAddr).unwrap(),
+            Frame::set(0, 3, Property::NetIfUp, Bytes::from_static(&[0x01])).unwrap(),
+            Frame::new(
+                Header::new(0, 4),
+                Command::Vendor(0x3C00, Bytes::from_static(&[0x01, 0x02])),
+            ),
+        ] {
+            assert_eq!(frame.encoded_len(), frame.to_wire_bytes().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn frame_with_header_retags_noop() {
+        let frame = Frame::new(Header::new(0, 1), Command::Noop);
+        let frame = frame.with_header(Header::new(0, 7));
+
+        assert_eq!(frame.header().tid(), 7);
+        assert_eq!(frame.to_wire_bytes(), Ok(Bytes::from_static(&[0x87, 0x00])));
+    }
+
+    #[test]
+    fn frame_map_command() {
+        let frame = Frame::new(Header::new(0, 1), Command::Noop).map_command(|_| Command::Reset);
+        assert_eq!(frame, Frame::new(Header::new(0, 1), Command::Reset));
+    }
+
+    #[test]
+    fn frame_hexdump() {
+        let frame = Frame::new(Header::new(0, 1), Command::Noop);
+        assert_eq!(frame.hexdump().unwrap().to_string(), "81 00");
+    }
+
+    #[test]
+    fn parse_hexdump() {
+        let parse = |s| HexDump::parse(s).map(HexDump::into_bytes);
+
+        assert_eq!(
+            parse("81 06 00 00"),
+            Some(Bytes::from_static(&[0x81, 0x06, 0x00, 0x00]))
+        );
+        assert_eq!(
+            parse("e8030000"),
+            Some(Bytes::from_static(&[0xE8, 0x03, 0x00, 0x00]))
+        );
+        assert_eq!(parse(""), Some(Bytes::new()));
+        assert_eq!(parse("8 1 0"), None);
+        assert_eq!(parse("zz"), None);
+        assert_eq!(parse("+f"), None);
+    }
+
+    #[test]
+    fn frame_is_broadcast() {
+        assert!(Frame::new(Header::new(0, 0), Command::Noop).is_broadcast());
+        assert!(!Frame::new(Header::new(0, 1), Command::Noop).is_broadcast());
+    }
+
+    #[test]
+    fn stream_payload_debug() {
+        let frame = Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(
+                Property::Stream(PropertyStream::Debug),
+                Bytes::from_static(b"boot\n"),
+            ),
+        );
+        assert_eq!(
+            frame.stream_payload(),
+            Some((PropertyStream::Debug, Bytes::from_static(b"boot\n")))
+        );
+    }
+
+    #[test]
+    fn stream_payload_net() {
+        let payload = Bytes::from_static(&[0x03, 0x00, 0x60, 0x00, 0x00]);
+        let frame = Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(Property::Stream(PropertyStream::Net), payload.clone()),
+        );
+        assert_eq!(frame.stream_payload(), Some((PropertyStream::Net, payload)));
+
+        let frame = Frame::new(
+            Header::new(0, 0),
+            Command::PropertyValueIs(Property::NcpVersion, Bytes::new()),
+        );
+        assert_eq!(frame.stream_payload(), None);
+    }
+
+    #[test]
+    fn last_status_ok() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(&[0x00])),
+        );
+        assert_eq!(frame.last_status(), Some(Ok(Status::Ok)));
+    }
+
+    #[test]
+    fn last_status_none_for_other_commands() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::NcpVersion, Bytes::from_static(&[0x00])),
+        );
+        assert_eq!(frame.last_status(), None);
+    }
+
+    #[test]
+    fn last_status_empty_payload() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::new()),
+        );
+        assert_eq!(frame.last_status(), Some(Err(Error::PacketLength(0))));
+    }
+
+    #[test]
+    fn ncp_version_errors() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(
+                Property::NcpVersion,
+                Bytes::from_static(&[0x4F, 0xFF, 0x00]),
+            ),
+        );
+        assert!(matches!(
+            frame.ncp_version(),
+            Some(Err(Error::DatatypeParseU8(_)))
+        ));
+
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::NcpVersion, Bytes::from_static(b"OT")),
+        );
+        assert_eq!(frame.ncp_version(), Some(Err(Error::PacketLength(2))));
+
+        let frame = Frame::get(0, 1, Property::NcpVersion).unwrap();
+        assert_eq!(frame.ncp_version(), None);
+    }
+
+    #[test]
+    fn validate_accepts_well_formed_values() {
+        for (prop, value) in [
+            (Property::LastStatus, &[0x00][..]),
+            (Property::ProtocolVersion, &[0x04, 0x03][..]),
+            (Property::NcpVersion, &b"OPENTHREAD/1.0\0"[..]),
+            (Property::NetIfUp, &[0x01][..]),
+            (Property::Mac15_4PanId, &[0xCD, 0xAB][..]),
+            (Property::Caps, &[0x05, 0x06, 0x07][..]),
+        ] {
+            let frame = Frame::new(
+                Header::new(0, 1),
+                Command::PropertyValueIs(prop, Bytes::from_static(value)),
+            );
+            assert_eq!(frame.validate(), Ok(()));
+        }
+
+        assert_eq!(
+            Frame::new(Header::new(0, 1), Command::Noop).validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_rejects_bogus_last_status() {
+        for value in [
+            &[][..],
+            &[0x00, 0x00][..],
+            &[0x80, 0x80, 0x80, 0x80, 0x80][..],
+        ] {
+            let frame = Frame::new(
+                Header::new(0, 1),
+                Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(value)),
+            );
+            assert_eq!(
+                frame.validate(),
+                Err(Error::PropertyValue(Property::LastStatus))
+            );
+        }
+    }
+
+    #[test]
+    fn validate_rejects_short_set() {
+        let frame = Frame::set(
+            0,
+            1,
+            Property::MacDataPollPeriod,
+            Bytes::from_static(&[0x01]),
+        )
+        .unwrap();
+        assert_eq!(
+            frame.validate(),
+            Err(Error::PropertyValue(Property::MacDataPollPeriod))
+        );
+    }
+
+    #[test]
+    fn last_status_reserved_status() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(&[0x63])),
+        );
+        assert_eq!(frame.last_status(), Some(Ok(Status::Reserved(0x63))));
+    }
+
+    #[test]
+    fn last_status_multi_byte_code() {
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(&[0x80, 0x78])),
+        );
+        assert_eq!(frame.last_status(), Some(Ok(Status::Vendor(15360))));
+        assert_eq!(frame.validate(), Ok(()));
+
+        let frame = Frame::new(
+            Header::new(0, 1),
+            Command::PropertyValueIs(Property::LastStatus, Bytes::from_static(&[0x80])),
+        );
+        assert_eq!(frame.last_status(), Some(Err(Error::PackedU32ByteCount)));
+    }
 }
@@ -1,54 +1,396 @@
-use crate::{Frame, HdlcLiteFrame};
-use bytes::BytesMut;
+use crate::{codec::CrcMode, Error, Frame, HdlcLiteFrame};
+use bytes::{Buf, Bytes, BytesMut};
 use std::io;
 use tokio_util::codec::{Decoder, Encoder};
 
-#[derive(Debug, Default)]
-pub struct HdlcCodec;
+/// Encode and decode [`Frame`]s in HDLC-lite framing.
+///
+/// Frames longer than the maximum frame length, [`HdlcLiteFrame::MAX_FRAME_LEN`] by default, are rejected in both
+/// directions so a misbehaving device cannot force unbounded buffering.
+///
+/// Errors are reported as [`Error`], so callers can match on the variant, for example [`Error::HdlcChecksum`], to
+/// decide whether to keep reading. Errors from the underlying transport are converted to [`Error::Io`].
+#[derive(Debug)]
+pub struct HdlcCodec {
+    max_frame_len: usize,
+    crc_mode: CrcMode,
+}
+
+impl HdlcCodec {
+    /// Create a new [`HdlcCodec`] using [`HdlcLiteFrame::MAX_FRAME_LEN`] and [`CrcMode::X25`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit frames to `max_frame_len` encoded bytes between delimiters.
+    pub fn with_max_frame_len(self, max_frame_len: usize) -> Self {
+        Self {
+            max_frame_len,
+            ..self
+        }
+    }
+
+    /// Set the checksum used on frames in both directions.
+    pub fn with_crc_mode(self, crc_mode: CrcMode) -> Self {
+        Self { crc_mode, ..self }
+    }
+
+    /// Get the maximum number of encoded bytes between delimiters.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Get the checksum used on frames.
+    pub fn crc_mode(&self) -> CrcMode {
+        self.crc_mode
+    }
+}
+
+impl Default for HdlcCodec {
+    fn default() -> Self {
+        Self {
+            max_frame_len: HdlcLiteFrame::MAX_FRAME_LEN,
+            crc_mode: CrcMode::default(),
+        }
+    }
+}
 
 impl Encoder<Frame> for HdlcCodec {
-    type Error = std::io::Error;
+    type Error = Error;
 
     fn encode(&mut self, item: Frame, src: &mut BytesMut) -> Result<(), Self::Error> {
-        let hdlc_frame = HdlcLiteFrame::new(item);
-        match hdlc_frame.encode(src) {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                eprintln!("Frame encode error: {:?}", e);
-                Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("Encoder error: {e:?}"),
-                ))
-            }
-        }
+        let hdlc_frame = HdlcLiteFrame::new(item).with_crc_mode(self.crc_mode);
+        hdlc_frame.encode_with_max_len(src, self.max_frame_len)
     }
 }
 
 impl Decoder for HdlcCodec {
     type Item = Frame;
-    type Error = std::io::Error;
+    type Error = Error;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
         if src.is_empty() {
             return Ok(None);
         }
 
-        if let Some(b) = HdlcLiteFrame::find_frame(&src.clone().freeze()) {
-            // Split data from src so the buffer advances
-            let frame = src.split_to(b.1 + 1).freeze().slice(b.0..);
+        split_frame(src, self.max_frame_len, self.crc_mode)
+            .map(|(_, result)| result)
+            .transpose()
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None => end_of_stream(src).map(|_| None),
+        }
+    }
+}
+
+/// An item produced by [`HdlcPassthroughCodec`].
+#[derive(Debug, PartialEq)]
+pub enum DecodedItem {
+    /// A frame that decoded successfully.
+    Frame(Frame),
+
+    /// A delimited frame that failed to decode, along with the raw bytes that were received.
+    Error { raw: Bytes, error: Error },
+}
+
+/// A variant of [`HdlcCodec`] for monitoring and debugging tools.
+///
+/// Frames that fail to decode are passed through as [`DecodedItem::Error`] instead of erroring the stream,
+/// so consumers see both good and corrupt frames.
+#[derive(Debug, Default)]
+pub struct HdlcPassthroughCodec {
+    codec: HdlcCodec,
+}
+
+impl HdlcPassthroughCodec {
+    /// Create a new [`HdlcPassthroughCodec`] using [`HdlcLiteFrame::MAX_FRAME_LEN`] and [`CrcMode::X25`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit frames to `max_frame_len` encoded bytes between delimiters.
+    pub fn with_max_frame_len(self, max_frame_len: usize) -> Self {
+        Self {
+            codec: self.codec.with_max_frame_len(max_frame_len),
+        }
+    }
+
+    /// Set the checksum used on frames in both directions.
+    pub fn with_crc_mode(self, crc_mode: CrcMode) -> Self {
+        Self {
+            codec: self.codec.with_crc_mode(crc_mode),
+        }
+    }
+}
+
+impl Encoder<Frame> for HdlcPassthroughCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: Frame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.codec.encode(item, dst)
+    }
+}
+
+impl Decoder for HdlcPassthroughCodec {
+    type Item = DecodedItem;
+    type Error = Error;
 
-            return match HdlcLiteFrame::decode(&frame) {
-                Ok(f) => Ok(Some(f.into_inner())),
-                Err(e) => {
-                    eprintln!("Frame decode error: {:?}", e);
-                    Err(io::Error::new(
-                        io::ErrorKind::Other,
-                        format!("Decoder error: {e:?}"),
-                    ))
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let item =
+            split_frame(src, self.codec.max_frame_len, self.codec.crc_mode).map(|(raw, result)| {
+                match result {
+                    Ok(frame) => DecodedItem::Frame(frame),
+                    Err(error) => DecodedItem::Error { raw, error },
                 }
-            };
+            });
+
+        Ok(item)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(item) => Ok(Some(item)),
+            None => end_of_stream(src).map(|_| None),
+        }
+    }
+}
+
+/// Check what is left in the buffer once the stream has ended and no more frames can be decoded.
+///
+/// The closing delimiter of the last frame is always left behind by [`split_frame`], so a buffer of only delimiters
+/// is discarded. Anything else is a frame that was cut off and is returned as an [`Error::Io`].
+fn end_of_stream(src: &mut BytesMut) -> Result<(), Error> {
+    if src
+        .iter()
+        .all(|&byte| byte == HdlcLiteFrame::FRAME_DELIMITER_FLAG)
+    {
+        src.clear();
+        return Ok(());
+    }
+
+    Err(io::Error::new(io::ErrorKind::UnexpectedEof, "bytes remaining on stream").into())
+}
+
+/// Split the next delimited frame from the buffer and decode it.
+///
+/// Noise before the first delimiter and empty frames are discarded. Returns the raw bytes of the frame along with
+/// the decode result, or `None` if no full frame is available yet.
+///
+/// A frame with more than `max_len` bytes between its delimiters is returned as [`Error::PacketLength`]. This is
+/// also returned for a partial frame as soon as it exceeds the limit, which discards the bytes received so far.
+fn split_frame(
+    src: &mut BytesMut,
+    max_len: usize,
+    crc_mode: CrcMode,
+) -> Option<(Bytes, Result<Frame, Error>)> {
+    // Bytes before the first delimiter can never be part of a frame, drop them so noise doesn't build up
+    match src
+        .iter()
+        .position(|&byte| byte == HdlcLiteFrame::FRAME_DELIMITER_FLAG)
+    {
+        Some(pos) => src.advance(pos),
+        None => {
+            src.clear();
+            return None;
         }
+    }
+
+    while let Some((start, end)) = HdlcLiteFrame::find_frame_in(src) {
+        // Leave the closing delimiter in the buffer as it may also open the next frame
+        let frame = Bytes::copy_from_slice(&src[start..=end]);
+        src.advance(end);
+
+        let len = end - start - 1;
+        if len > max_len {
+            return Some((frame, Err(Error::PacketLength(len))));
+        }
+
+        match HdlcLiteFrame::decode_with_crc_mode(&frame, crc_mode) {
+            // Empty frames carry no data, skip them and look for the next frame
+            Err(Error::PacketLength(0)) => continue,
+            result => return Some((frame, result.map(HdlcLiteFrame::into_inner))),
+        }
+    }
+
+    // The buffer now starts with a run of delimiters, followed by any partial frame
+    let start = src
+        .iter()
+        .position(|&byte| byte != HdlcLiteFrame::FRAME_DELIMITER_FLAG)
+        .unwrap_or(src.len());
+    let len = src.len() - start;
+    if len > max_len {
+        return Some((src.split().freeze(), Err(Error::PacketLength(len))));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, Header};
+    use bytes::BufMut;
+
+    // Noise from a device booting, ending in the start of a frame that was cut off
+    const TEST_DESYNC_STR: [u8; 24] = [
+        0xc2, 0x5a, 0xa7, 0xaf, 0x97, 0xb1, 0x54, 0x99, 0x2b, 0xf5, 0x06, 0xe5, 0x7b, 0x5d, 0xdc,
+        0x8d, 0x24, 0x81, 0x3f, 0x7e, 0x7e, 0x80, 0x06, 0x73,
+    ];
+
+    const TEST_REQ_NOOP_ARRAY: [u8; 6] = [0x7e, 0x81, 0x00, 0x53, 0x9a, 0x7e];
+
+    #[test]
+    fn decode_skips_empty_frames() {
+        let mut src = BytesMut::from(&[0x7E, 0x7E, 0x7E, 0x81, 0x00, 0x53, 0x9A, 0x7E][..]);
+        let frame = HdlcCodec::new().decode(&mut src).unwrap();
+        assert_eq!(
+            frame,
+            Some(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+        );
+        assert_eq!(&src[..], &[0x7E]);
+    }
+
+    #[test]
+    fn decode_eof_after_last_frame() {
+        let noop = Frame::new(Header::new(0x00, 0x01), Command::Noop);
+
+        let mut codec = HdlcCodec::new();
+        let mut src = BytesMut::from(&TEST_REQ_NOOP_ARRAY[..]);
+        assert_eq!(codec.decode_eof(&mut src), Ok(Some(noop.clone())));
+        assert_eq!(codec.decode_eof(&mut src), Ok(None));
+        assert!(src.is_empty());
+
+        let mut codec = HdlcPassthroughCodec::new();
+        let mut src = BytesMut::from(&TEST_REQ_NOOP_ARRAY[..]);
+        assert_eq!(
+            codec.decode_eof(&mut src).unwrap(),
+            Some(DecodedItem::Frame(noop))
+        );
+        assert_eq!(codec.decode_eof(&mut src).unwrap(), None);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn decode_eof_in_partial_frame() {
+        let mut src = BytesMut::from(&TEST_REQ_NOOP_ARRAY[..4]);
+        assert!(matches!(
+            HdlcCodec::new().decode_eof(&mut src),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn decode_discards_leading_noise() {
+        let mut src = BytesMut::from(&[0xC2, 0x5A, 0xA7, 0xAF][..]);
+        assert_eq!(HdlcCodec::new().decode(&mut src).unwrap(), None);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn decode_recovers_after_desync() {
+        let mut src = BytesMut::from(&TEST_DESYNC_STR[..]);
+        assert_eq!(HdlcCodec::new().decode(&mut src).unwrap(), None);
+        assert_eq!(&src[..], &[0x7E, 0x7E, 0x80, 0x06, 0x73]);
+
+        src.extend_from_slice(&TEST_REQ_NOOP_ARRAY);
+
+        // The truncated frame left over from the desync fails its checksum
+        assert!(HdlcCodec::new().decode(&mut src).is_err());
+        assert_eq!(
+            HdlcCodec::new().decode(&mut src).unwrap(),
+            Some(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+        );
+    }
+
+    #[test]
+    fn decode_reports_checksum_error() {
+        let mut src = BytesMut::from(&[0x7E, 0x81, 0x00, 0x00, 0x9A, 0x7E][..]);
+        src.extend_from_slice(&TEST_REQ_NOOP_ARRAY[1..]);
+
+        let mut codec = HdlcCodec::new();
+        assert_eq!(codec.decode(&mut src), Err(Error::HdlcChecksum(0x9A53)));
+        assert_eq!(
+            codec.decode(&mut src),
+            Ok(Some(Frame::new(Header::new(0x00, 0x01), Command::Noop)))
+        );
+    }
+
+    #[test]
+    fn decode_rejects_oversized_frame() {
+        let mut codec = HdlcCodec::new().with_max_frame_len(4);
+
+        let mut src = BytesMut::from(&[0x7E, 0x81, 0x00, 0x00, 0x00, 0x00, 0x7E][..]);
+        src.extend_from_slice(&TEST_REQ_NOOP_ARRAY[1..]);
+
+        assert!(codec.decode(&mut src).is_err());
+        assert_eq!(
+            codec.decode(&mut src).unwrap(),
+            Some(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+        );
+    }
+
+    #[test]
+    fn decode_bounds_partial_frame() {
+        let mut src = BytesMut::from(&[0x7E, 0x7E][..]);
+        src.extend_from_slice(&[0x00; HdlcLiteFrame::MAX_FRAME_LEN]);
+        assert_eq!(HdlcCodec::new().decode(&mut src).unwrap(), None);
+
+        src.put_u8(0x00);
+        let item = HdlcPassthroughCodec::new().decode(&mut src).unwrap();
+        assert!(matches!(
+            item,
+            Some(DecodedItem::Error {
+                error: Error::PacketLength(len),
+                ..
+            }) if len == HdlcLiteFrame::MAX_FRAME_LEN + 1
+        ));
+        assert!(src.is_empty());
+
+        // The rest of the oversized frame is discarded as noise until the next delimiter
+        src.extend_from_slice(&[0x00, 0x00]);
+        src.extend_from_slice(&TEST_REQ_NOOP_ARRAY);
+        assert_eq!(
+            HdlcCodec::new().decode(&mut src).unwrap(),
+            Some(Frame::new(Header::new(0x00, 0x01), Command::Noop))
+        );
+    }
+
+    #[test]
+    fn codec_round_trip_without_crc() {
+        let mut codec = HdlcCodec::new().with_crc_mode(CrcMode::None);
+        let frame = Frame::new(Header::new(0x00, 0x01), Command::Noop);
+
+        let mut src = BytesMut::new();
+        codec.encode(frame.clone(), &mut src).unwrap();
+        assert_eq!(&src[..], &[0x7E, 0x81, 0x00, 0x7E]);
+        assert_eq!(codec.decode(&mut src).unwrap(), Some(frame));
+    }
+
+    #[test]
+    fn passthrough_yields_corrupt_frames() {
+        let corrupt = [0x7E, 0x81, 0x00, 0x00, 0x9A, 0x7E];
+        let mut src = BytesMut::from(&corrupt[..]);
+        src.extend_from_slice(&[0x7E, 0x81, 0x00, 0x53, 0x9A, 0x7E]);
+
+        let item = HdlcPassthroughCodec::new().decode(&mut src).unwrap();
+        assert_eq!(
+            item,
+            Some(DecodedItem::Error {
+                raw: Bytes::copy_from_slice(&corrupt),
+                error: Error::HdlcChecksum(0x9A53),
+            })
+        );
 
-        Ok(None)
+        let item = HdlcPassthroughCodec::new().decode(&mut src).unwrap();
+        assert_eq!(
+            item,
+            Some(DecodedItem::Frame(Frame::new(
+                Header::new(0x00, 0x01),
+                Command::Noop
+            )))
+        );
     }
 }
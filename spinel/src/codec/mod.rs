@@ -1,16 +1,29 @@
 cfg_if::cfg_if! {
     if #[cfg(feature = "std")] {
+        mod debug_lines;
         mod hdlc;
-        pub use hdlc::HdlcCodec;
+        pub use debug_lines::DebugLines;
+        pub use hdlc::{DecodedItem, HdlcCodec, HdlcPassthroughCodec};
+        pub use datatype::{pack, unpack, Value};
     }
 }
 
+mod accumulator;
 mod command;
 pub(crate) mod datatype;
 mod frame;
 mod property;
 
-pub use command::Command;
-pub use datatype::{PackedU32, Status};
-pub use frame::{Frame, HdlcLiteFrame, Header};
+pub use accumulator::FrameAccumulator;
+pub use command::{Command, CommandRef};
+pub use datatype::{
+    decode_capability_list, decode_ipv6_list, decode_struct_list, BeaconInfo, Capability,
+    CapabilityList, ChannelMask, Decode, Encode, EnergyScanResult, Eui48, Eui64, HostPowerState,
+    InterfaceType, Ipv6Address, Ipv6AddressList, MacCounters, McuPowerState, NetStreamFrame,
+    PackedI32, PackedU32, ProtocolVersion, ResetReason, ScanState, Status, StreamMetadata,
+    StructList,
+};
+pub use frame::{
+    CrcMode, Frame, FrameRef, HdlcLiteFrame, Header, HexDump, InstanceId, SpiFrame, SpiHeader,
+};
 pub use property::{Property, PropertyStream};
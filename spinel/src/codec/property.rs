@@ -1,7 +1,7 @@
 use crate::error::Error;
-use core::fmt;
+use core::{fmt, str::FromStr};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PropertyStream {
     /// This stream provides the capability of sending human-readable debugging output which may be displayed in
     /// the host logs.
@@ -11,6 +11,10 @@ pub enum PropertyStream {
     /// to be sent with this property ID.
     Debug,
 
+    /// Raw radio frames, such as IEEE 802.15.4 frames including their MAC header, sent to and received from the
+    /// radio without being processed by the network stack.
+    Raw,
+
     /// This stream provides the capability of sending and receiving data packets to and from the currently attached
     /// network.
     ///
@@ -25,10 +29,23 @@ pub enum PropertyStream {
     Log,
 }
 
+impl PropertyStream {
+    /// Check if the host can send data into the stream.
+    ///
+    /// [`PropertyStream::Debug`] and [`PropertyStream::Log`] only carry output from the device.
+    pub fn is_writable(&self) -> bool {
+        match self {
+            PropertyStream::Raw | PropertyStream::Net | PropertyStream::NetInsecure => true,
+            PropertyStream::Debug | PropertyStream::Log => false,
+        }
+    }
+}
+
 impl fmt::Display for PropertyStream {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             PropertyStream::Debug => write!(f, "Debug"),
+            PropertyStream::Raw => write!(f, "Raw"),
             PropertyStream::Net => write!(f, "Net"),
             PropertyStream::NetInsecure => write!(f, "NetInsecure"),
             PropertyStream::Log => write!(f, "Log"),
@@ -37,7 +54,7 @@ impl fmt::Display for PropertyStream {
 }
 
 /// Spinel Properties
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Property {
     /// Describes the status of the last operation encoded as a packed unsigned integer.
     ///
@@ -54,6 +71,12 @@ pub enum Property {
     /// Identifies the network protocol for the device.
     InterfaceType,
 
+    /// The capabilities supported by the device, encoded as an array of packed unsigned integers.
+    ///
+    /// See [`Capability`](crate::codec::Capability) and
+    /// [`decode_capability_list`](crate::codec::decode_capability_list).
+    Caps,
+
     /// Special properties representing streams of data.
     ///
     /// All stream properties emit changes asynchronously using [`Command::PropertyValueIs`](crate::Command::PropertyValueIs)
@@ -66,8 +89,123 @@ pub enum Property {
     /// Typically read-only, but may be writable for some vendor defined circumstances.
     HardwareAddress,
 
-    /// Transmit power of the radio in dBm.
-    PhysicalTxPower,
+    /// The power state of the device, encoded as a `uint8`.
+    ///
+    /// Deprecated in favour of [`Property::McuPowerState`], but still the only power state property on older
+    /// firmware. See [`McuPowerState::decode_legacy`](crate::codec::McuPowerState::decode_legacy).
+    PowerState,
+
+    /// The power state of the device's MCU, encoded as a `uint8`. See
+    /// [`McuPowerState`](crate::codec::McuPowerState).
+    McuPowerState,
+
+    /// The power state of the host, encoded as a `uint8`. See [`HostPowerState`](crate::codec::HostPowerState).
+    HostPowerState,
+
+    /// The current channel of the radio, encoded as a `uint8`.
+    PhyChannel,
+
+    /// The list of channels supported by the radio, encoded as an array of `uint8`.
+    PhyChanSupported,
+
+    /// Transmit power of the radio in dBm, encoded as an `int8`.
+    PhyTxPower,
+
+    /// The current RSSI of the radio in dBm, encoded as an `int8`.
+    PhyRssi,
+
+    /// The current scan state, encoded as a `uint8`. See [`ScanState`](crate::codec::ScanState).
+    MacScanState,
+
+    /// The channels to scan, encoded as a list of `uint8` channels. See [`ChannelMask`](crate::codec::ChannelMask).
+    MacScanMask,
+
+    /// The time to spend on each channel during a scan in milliseconds, encoded as a `uint16`.
+    MacScanPeriod,
+
+    /// The result of an energy scan on one channel, sent asynchronously by the device during a scan.
+    /// See [`EnergyScanResult`](crate::codec::EnergyScanResult).
+    MacEnergyScanResult,
+
+    /// A beacon received during an active scan, sent asynchronously by the device during a scan.
+    /// See [`BeaconInfo`](crate::codec::BeaconInfo).
+    MacScanBeacon,
+
+    /// The interval at which a sleepy end device polls its parent for data in milliseconds, encoded as a `uint32`.
+    MacDataPollPeriod,
+
+    /// The 802.15.4 long address of the device, encoded as an EUI64.
+    Mac15_4LAddr,
+
+    /// The 802.15.4 short address of the device, encoded as a `uint16`.
+    Mac15_4SAddr,
+
+    /// The 802.15.4 PAN ID, encoded as a `uint16`.
+    Mac15_4PanId,
+
+    /// The extended MAC address used on the network, encoded as an EUI64.
+    ///
+    /// Unlike [`Property::HardwareAddress`] this is typically randomized when a network is formed or joined.
+    Mac15_4ExtendedAddr,
+
+    /// Whether the network interface is up, encoded as a `bool`.
+    NetIfUp,
+
+    /// Whether the Thread stack is running, encoded as a `bool`.
+    NetStackUp,
+
+    /// The role of the device on the network (detached, child, router, leader), encoded as a `uint8`.
+    NetRole,
+
+    /// The name of the network, encoded as a UTF8 string.
+    NetNetworkName,
+
+    /// The extended PAN ID of the network, encoded as 8 bytes of data.
+    NetXpanId,
+
+    /// The network key, encoded as 16 bytes of data.
+    NetNetworkKey,
+
+    /// The key sequence counter of the network key, encoded as a `uint32`.
+    NetKeySequenceCounter,
+
+    /// The partition ID of the partition the device is attached to, encoded as a `uint32`.
+    NetPartitionId,
+
+    /// Resets all counters when set to `1`, encoded as a `uint8`. Write only.
+    CntrReset,
+
+    /// The total number of transmitted packets, encoded as a `uint32`.
+    CntrTxPktTotal,
+
+    /// The number of transmitted packets that were acknowledged, encoded as a `uint32`.
+    CntrTxPktAcked,
+
+    /// The number of retransmission attempts, encoded as a `uint32`.
+    CntrTxPktRetry,
+
+    /// The number of transmissions that failed CCA, encoded as a `uint32`.
+    CntrTxErrCca,
+
+    /// The number of transmissions that were aborted, encoded as a `uint32`.
+    CntrTxErrAbort,
+
+    /// The total number of received packets, encoded as a `uint32`.
+    CntrRxPktTotal,
+
+    /// The number of received packets with a bad FCS, encoded as a `uint32`.
+    CntrRxErrBadFcs,
+
+    /// The number of received packets dropped for other reasons, encoded as a `uint32`.
+    CntrRxErrOther,
+
+    /// The number of duplicate packets received, encoded as a `uint32`.
+    CntrRxPktDup,
+
+    /// A vendor specific property with an id in the vendor range (`0x3C00` to `0x3FFF`).
+    ///
+    /// The value is not interpreted by this crate.
+    Vendor(u32),
 }
 
 impl fmt::Display for Property {
@@ -77,9 +215,45 @@ impl fmt::Display for Property {
             Property::ProtocolVersion => write!(f, "ProtocolVersion"),
             Property::NcpVersion => write!(f, "NcpVersion"),
             Property::InterfaceType => write!(f, "InterfaceType"),
+            Property::Caps => write!(f, "Caps"),
             Property::Stream(stream) => write!(f, "{}", stream),
             Property::HardwareAddress => write!(f, "HardwareAddress"),
-            Property::PhysicalTxPower => write!(f, "PhysicalTxPower"),
+            Property::PowerState => write!(f, "PowerState"),
+            Property::McuPowerState => write!(f, "McuPowerState"),
+            Property::HostPowerState => write!(f, "HostPowerState"),
+            Property::PhyChannel => write!(f, "PhyChannel"),
+            Property::PhyChanSupported => write!(f, "PhyChanSupported"),
+            Property::PhyTxPower => write!(f, "PhyTxPower"),
+            Property::PhyRssi => write!(f, "PhyRssi"),
+            Property::MacScanState => write!(f, "MacScanState"),
+            Property::MacScanMask => write!(f, "MacScanMask"),
+            Property::MacScanPeriod => write!(f, "MacScanPeriod"),
+            Property::MacEnergyScanResult => write!(f, "MacEnergyScanResult"),
+            Property::MacScanBeacon => write!(f, "MacScanBeacon"),
+            Property::MacDataPollPeriod => write!(f, "MacDataPollPeriod"),
+            Property::Mac15_4LAddr => write!(f, "Mac15_4LAddr"),
+            Property::Mac15_4SAddr => write!(f, "Mac15_4SAddr"),
+            Property::Mac15_4PanId => write!(f, "Mac15_4PanId"),
+            Property::Mac15_4ExtendedAddr => write!(f, "Mac15_4ExtendedAddr"),
+            Property::NetIfUp => write!(f, "NetIfUp"),
+            Property::NetStackUp => write!(f, "NetStackUp"),
+            Property::NetRole => write!(f, "NetRole"),
+            Property::NetNetworkName => write!(f, "NetNetworkName"),
+            Property::NetXpanId => write!(f, "NetXpanId"),
+            Property::NetNetworkKey => write!(f, "NetNetworkKey"),
+            Property::NetKeySequenceCounter => write!(f, "NetKeySequenceCounter"),
+            Property::NetPartitionId => write!(f, "NetPartitionId"),
+            Property::CntrReset => write!(f, "CntrReset"),
+            Property::CntrTxPktTotal => write!(f, "CntrTxPktTotal"),
+            Property::CntrTxPktAcked => write!(f, "CntrTxPktAcked"),
+            Property::CntrTxPktRetry => write!(f, "CntrTxPktRetry"),
+            Property::CntrTxErrCca => write!(f, "CntrTxErrCca"),
+            Property::CntrTxErrAbort => write!(f, "CntrTxErrAbort"),
+            Property::CntrRxPktTotal => write!(f, "CntrRxPktTotal"),
+            Property::CntrRxErrBadFcs => write!(f, "CntrRxErrBadFcs"),
+            Property::CntrRxErrOther => write!(f, "CntrRxErrOther"),
+            Property::CntrRxPktDup => write!(f, "CntrRxPktDup"),
+            Property::Vendor(id) => write!(f, "Vendor({:#x})", id),
         }
     }
 }
@@ -89,12 +263,121 @@ impl Property {
     const PROP_PROTOCOL_VERSION: u32 = 0x01;
     const PROP_NCP_VERSION: u32 = 0x02;
     const PROP_INTERFACE_TYPE: u32 = 0x03;
+    const PROP_CAPS: u32 = 0x05;
+    const PROP_POWER_STATE: u32 = 0x07;
     const PROP_HWADDR: u32 = 0x08;
-    const PROP_PHY_TX_POWER: u32 = 0x26;
+    const PROP_HOST_POWER_STATE: u32 = 0x0C;
+    const PROP_MCU_POWER_STATE: u32 = 0x0D;
+    const PROP_PHY_CHAN: u32 = 0x21;
+    const PROP_PHY_CHAN_SUPPORTED: u32 = 0x22;
+    const PROP_PHY_TX_POWER: u32 = 0x25;
+    const PROP_PHY_RSSI: u32 = 0x26;
+    const PROP_MAC_SCAN_STATE: u32 = 0x30;
+    const PROP_MAC_SCAN_MASK: u32 = 0x31;
+    const PROP_MAC_SCAN_PERIOD: u32 = 0x32;
+    const PROP_MAC_SCAN_BEACON: u32 = 0x33;
+    const PROP_MAC_15_4_LADDR: u32 = 0x34;
+    const PROP_MAC_15_4_SADDR: u32 = 0x35;
+    const PROP_MAC_15_4_PANID: u32 = 0x36;
+    const PROP_MAC_ENERGY_SCAN_RESULT: u32 = 0x39;
+    const PROP_MAC_DATA_POLL_PERIOD: u32 = 0x3A;
+    const PROP_NET_IF_UP: u32 = 0x41;
+    const PROP_NET_STACK_UP: u32 = 0x42;
+    const PROP_NET_ROLE: u32 = 0x43;
+    const PROP_NET_NETWORK_NAME: u32 = 0x44;
+    const PROP_NET_XPANID: u32 = 0x45;
+    const PROP_NET_NETWORK_KEY: u32 = 0x46;
+    const PROP_NET_KEY_SEQUENCE_COUNTER: u32 = 0x47;
+    const PROP_NET_PARTITION_ID: u32 = 0x48;
     const PROP_STREAM_DEBUG: u32 = 0x70;
-    const PROP_STREAM_NET: u32 = 0x71;
+    const PROP_STREAM_RAW: u32 = 0x71;
+    const PROP_STREAM_NET: u32 = 0x72;
     const PROP_STREAM_NET_INSECURE: u32 = 0x73;
     const PROP_STREAM_LOG: u32 = 0x74;
+    const PROP_CNTR_RESET: u32 = 0x500;
+    const PROP_CNTR_TX_PKT_TOTAL: u32 = 0x501;
+    const PROP_CNTR_TX_PKT_ACKED: u32 = 0x503;
+    const PROP_CNTR_TX_PKT_RETRY: u32 = 0x50A;
+    const PROP_CNTR_TX_ERR_CCA: u32 = 0x50B;
+    const PROP_CNTR_TX_ERR_ABORT: u32 = 0x50E;
+    const PROP_CNTR_RX_PKT_TOTAL: u32 = 0x564;
+    const PROP_CNTR_RX_ERR_BAD_FCS: u32 = 0x570;
+    const PROP_CNTR_RX_ERR_OTHER: u32 = 0x571;
+    const PROP_CNTR_RX_PKT_DUP: u32 = 0x572;
+    const PROP_MAC_EXTENDED_ADDR: u32 = 0x1302;
+    const PROP_VENDOR_BEGIN: u32 = 0x3C00;
+    const PROP_VENDOR_END: u32 = 0x4000;
+
+    const PROP_PHY_BEGIN: u32 = 0x20;
+    const PROP_PHY_END: u32 = 0x30;
+    const PROP_PHY_EXT_BEGIN: u32 = 0x1200;
+    const PROP_PHY_EXT_END: u32 = 0x1300;
+    const PROP_MAC_BEGIN: u32 = 0x30;
+    const PROP_MAC_END: u32 = 0x40;
+    const PROP_MAC_EXT_BEGIN: u32 = 0x1300;
+    const PROP_MAC_EXT_END: u32 = 0x1400;
+    const PROP_NET_BEGIN: u32 = 0x40;
+    const PROP_NET_END: u32 = 0x50;
+    const PROP_NET_EXT_BEGIN: u32 = 0x1400;
+    const PROP_NET_EXT_END: u32 = 0x1500;
+    const PROP_THREAD_BEGIN: u32 = 0x50;
+    const PROP_THREAD_END: u32 = 0x60;
+    const PROP_THREAD_EXT_BEGIN: u32 = 0x1500;
+    const PROP_THREAD_EXT_END: u32 = 0x1600;
+    const PROP_STREAM_BEGIN: u32 = 0x70;
+    const PROP_STREAM_END: u32 = 0x80;
+    const PROP_STREAM_EXT_BEGIN: u32 = 0x1700;
+    const PROP_STREAM_EXT_END: u32 = 0x1800;
+
+    /// Every [`Property`] with a name, in id order. Used to look up a property from its [`Property::name`].
+    const NAMED: &'static [Property] = &[
+        Property::LastStatus,
+        Property::ProtocolVersion,
+        Property::NcpVersion,
+        Property::InterfaceType,
+        Property::Caps,
+        Property::PowerState,
+        Property::HardwareAddress,
+        Property::HostPowerState,
+        Property::McuPowerState,
+        Property::PhyChannel,
+        Property::PhyChanSupported,
+        Property::PhyTxPower,
+        Property::PhyRssi,
+        Property::MacScanState,
+        Property::MacScanMask,
+        Property::MacScanPeriod,
+        Property::MacScanBeacon,
+        Property::Mac15_4LAddr,
+        Property::Mac15_4SAddr,
+        Property::Mac15_4PanId,
+        Property::MacEnergyScanResult,
+        Property::MacDataPollPeriod,
+        Property::NetIfUp,
+        Property::NetStackUp,
+        Property::NetRole,
+        Property::NetNetworkName,
+        Property::NetXpanId,
+        Property::NetNetworkKey,
+        Property::NetKeySequenceCounter,
+        Property::NetPartitionId,
+        Property::Stream(PropertyStream::Debug),
+        Property::Stream(PropertyStream::Raw),
+        Property::Stream(PropertyStream::Net),
+        Property::Stream(PropertyStream::NetInsecure),
+        Property::Stream(PropertyStream::Log),
+        Property::CntrReset,
+        Property::CntrTxPktTotal,
+        Property::CntrTxPktAcked,
+        Property::CntrTxPktRetry,
+        Property::CntrTxErrCca,
+        Property::CntrTxErrAbort,
+        Property::CntrRxPktTotal,
+        Property::CntrRxErrBadFcs,
+        Property::CntrRxErrOther,
+        Property::CntrRxPktDup,
+        Property::Mac15_4ExtendedAddr,
+    ];
 
     /// Byte representation of the [`Property`] on the wire
     pub fn id(&self) -> u32 {
@@ -103,14 +386,246 @@ impl Property {
             Property::ProtocolVersion => Self::PROP_PROTOCOL_VERSION,
             Property::NcpVersion => Self::PROP_NCP_VERSION,
             Property::InterfaceType => Self::PROP_INTERFACE_TYPE,
+            Property::Caps => Self::PROP_CAPS,
             Property::Stream(stream) => match stream {
                 PropertyStream::Debug => Self::PROP_STREAM_DEBUG,
+                PropertyStream::Raw => Self::PROP_STREAM_RAW,
                 PropertyStream::Net => Self::PROP_STREAM_NET,
                 PropertyStream::NetInsecure => Self::PROP_STREAM_NET_INSECURE,
                 PropertyStream::Log => Self::PROP_STREAM_LOG,
             },
             Property::HardwareAddress => Self::PROP_HWADDR,
-            Property::PhysicalTxPower => Self::PROP_PHY_TX_POWER,
+            Property::PowerState => Self::PROP_POWER_STATE,
+            Property::McuPowerState => Self::PROP_MCU_POWER_STATE,
+            Property::HostPowerState => Self::PROP_HOST_POWER_STATE,
+            Property::PhyChannel => Self::PROP_PHY_CHAN,
+            Property::PhyChanSupported => Self::PROP_PHY_CHAN_SUPPORTED,
+            Property::PhyTxPower => Self::PROP_PHY_TX_POWER,
+            Property::PhyRssi => Self::PROP_PHY_RSSI,
+            Property::MacScanState => Self::PROP_MAC_SCAN_STATE,
+            Property::MacScanMask => Self::PROP_MAC_SCAN_MASK,
+            Property::MacScanPeriod => Self::PROP_MAC_SCAN_PERIOD,
+            Property::MacEnergyScanResult => Self::PROP_MAC_ENERGY_SCAN_RESULT,
+            Property::MacScanBeacon => Self::PROP_MAC_SCAN_BEACON,
+            Property::MacDataPollPeriod => Self::PROP_MAC_DATA_POLL_PERIOD,
+            Property::Mac15_4LAddr => Self::PROP_MAC_15_4_LADDR,
+            Property::Mac15_4SAddr => Self::PROP_MAC_15_4_SADDR,
+            Property::Mac15_4PanId => Self::PROP_MAC_15_4_PANID,
+            Property::Mac15_4ExtendedAddr => Self::PROP_MAC_EXTENDED_ADDR,
+            Property::NetIfUp => Self::PROP_NET_IF_UP,
+            Property::NetStackUp => Self::PROP_NET_STACK_UP,
+            Property::NetRole => Self::PROP_NET_ROLE,
+            Property::NetNetworkName => Self::PROP_NET_NETWORK_NAME,
+            Property::NetXpanId => Self::PROP_NET_XPANID,
+            Property::NetNetworkKey => Self::PROP_NET_NETWORK_KEY,
+            Property::NetKeySequenceCounter => Self::PROP_NET_KEY_SEQUENCE_COUNTER,
+            Property::NetPartitionId => Self::PROP_NET_PARTITION_ID,
+            Property::CntrReset => Self::PROP_CNTR_RESET,
+            Property::CntrTxPktTotal => Self::PROP_CNTR_TX_PKT_TOTAL,
+            Property::CntrTxPktAcked => Self::PROP_CNTR_TX_PKT_ACKED,
+            Property::CntrTxPktRetry => Self::PROP_CNTR_TX_PKT_RETRY,
+            Property::CntrTxErrCca => Self::PROP_CNTR_TX_ERR_CCA,
+            Property::CntrTxErrAbort => Self::PROP_CNTR_TX_ERR_ABORT,
+            Property::CntrRxPktTotal => Self::PROP_CNTR_RX_PKT_TOTAL,
+            Property::CntrRxErrBadFcs => Self::PROP_CNTR_RX_ERR_BAD_FCS,
+            Property::CntrRxErrOther => Self::PROP_CNTR_RX_ERR_OTHER,
+            Property::CntrRxPktDup => Self::PROP_CNTR_RX_PKT_DUP,
+            Property::Vendor(id) => *id,
+        }
+    }
+
+    /// Check if the [`Property`] id is in one of the stream ranges (`0x70` to `0x7F` and `0x1700` to `0x17FF`).
+    pub fn is_stream(&self) -> bool {
+        matches!(
+            self.id(),
+            Self::PROP_STREAM_BEGIN..Self::PROP_STREAM_END
+                | Self::PROP_STREAM_EXT_BEGIN..Self::PROP_STREAM_EXT_END
+        )
+    }
+
+    /// Check if the [`Property`] id is in one of the PHY ranges (`0x20` to `0x2F` and `0x1200` to `0x12FF`).
+    pub fn is_phy(&self) -> bool {
+        matches!(
+            self.id(),
+            Self::PROP_PHY_BEGIN..Self::PROP_PHY_END
+                | Self::PROP_PHY_EXT_BEGIN..Self::PROP_PHY_EXT_END
+        )
+    }
+
+    /// Check if the [`Property`] id is in one of the MAC ranges (`0x30` to `0x3F` and `0x1300` to `0x13FF`).
+    pub fn is_mac(&self) -> bool {
+        matches!(
+            self.id(),
+            Self::PROP_MAC_BEGIN..Self::PROP_MAC_END
+                | Self::PROP_MAC_EXT_BEGIN..Self::PROP_MAC_EXT_END
+        )
+    }
+
+    /// Check if the [`Property`] id is in one of the NET ranges (`0x40` to `0x4F` and `0x1400` to `0x14FF`).
+    pub fn is_net(&self) -> bool {
+        matches!(
+            self.id(),
+            Self::PROP_NET_BEGIN..Self::PROP_NET_END
+                | Self::PROP_NET_EXT_BEGIN..Self::PROP_NET_EXT_END
+        )
+    }
+
+    /// Check if the [`Property`] id is in one of the Thread ranges (`0x50` to `0x5F` and `0x1500` to `0x15FF`).
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self.id(),
+            Self::PROP_THREAD_BEGIN..Self::PROP_THREAD_END
+                | Self::PROP_THREAD_EXT_BEGIN..Self::PROP_THREAD_EXT_END
+        )
+    }
+
+    /// Check if the host can set the [`Property`] with [`Command::PropertyValueSet`](crate::Command::PropertyValueSet).
+    ///
+    /// Properties that are only reported by the device, such as [`Property::LastStatus`] or the counters, and the
+    /// output only streams return `false`. Vendor properties are assumed to be writable.
+    pub fn is_writable(&self) -> bool {
+        match self {
+            Property::Stream(stream) => stream.is_writable(),
+            Property::LastStatus
+            | Property::ProtocolVersion
+            | Property::NcpVersion
+            | Property::InterfaceType
+            | Property::Caps
+            | Property::PhyChanSupported
+            | Property::PhyRssi
+            | Property::MacEnergyScanResult
+            | Property::MacScanBeacon
+            | Property::CntrTxPktTotal
+            | Property::CntrTxPktAcked
+            | Property::CntrTxPktRetry
+            | Property::CntrTxErrCca
+            | Property::CntrTxErrAbort
+            | Property::CntrRxPktTotal
+            | Property::CntrRxErrBadFcs
+            | Property::CntrRxErrOther
+            | Property::CntrRxPktDup => false,
+            Property::HardwareAddress
+            | Property::PowerState
+            | Property::McuPowerState
+            | Property::HostPowerState
+            | Property::PhyChannel
+            | Property::PhyTxPower
+            | Property::MacScanState
+            | Property::MacScanMask
+            | Property::MacScanPeriod
+            | Property::MacDataPollPeriod
+            | Property::Mac15_4LAddr
+            | Property::Mac15_4SAddr
+            | Property::Mac15_4PanId
+            | Property::Mac15_4ExtendedAddr
+            | Property::NetIfUp
+            | Property::NetStackUp
+            | Property::NetRole
+            | Property::NetNetworkName
+            | Property::NetXpanId
+            | Property::NetNetworkKey
+            | Property::NetKeySequenceCounter
+            | Property::NetPartitionId
+            | Property::CntrReset
+            | Property::Vendor(_) => true,
+        }
+    }
+
+    /// Length of the value of the [`Property`] in bytes, if it always has the same length.
+    ///
+    /// Returns `None` for properties encoded as strings, lists or packed integers, and for streams and vendor
+    /// properties.
+    pub fn fixed_len(&self) -> Option<usize> {
+        match self {
+            Property::PowerState
+            | Property::McuPowerState
+            | Property::HostPowerState
+            | Property::PhyChannel
+            | Property::PhyTxPower
+            | Property::PhyRssi
+            | Property::MacScanState
+            | Property::NetIfUp
+            | Property::NetStackUp
+            | Property::NetRole
+            | Property::CntrReset => Some(1),
+            Property::MacScanPeriod
+            | Property::MacEnergyScanResult
+            | Property::Mac15_4SAddr
+            | Property::Mac15_4PanId => Some(2),
+            Property::MacDataPollPeriod
+            | Property::NetKeySequenceCounter
+            | Property::NetPartitionId
+            | Property::CntrTxPktTotal
+            | Property::CntrTxPktAcked
+            | Property::CntrTxPktRetry
+            | Property::CntrTxErrCca
+            | Property::CntrTxErrAbort
+            | Property::CntrRxPktTotal
+            | Property::CntrRxErrBadFcs
+            | Property::CntrRxErrOther
+            | Property::CntrRxPktDup => Some(4),
+            Property::HardwareAddress
+            | Property::Mac15_4LAddr
+            | Property::Mac15_4ExtendedAddr
+            | Property::NetXpanId => Some(8),
+            Property::NetNetworkKey => Some(16),
+            _ => None,
+        }
+    }
+
+    /// Short name of the [`Property`], the `SPINEL_PROP_*` name from `spinel.h` in kebab case.
+    ///
+    /// This is the name accepted by [`Property::from_str`]. All [`Property::Vendor`] properties are named `vendor`,
+    /// they can only be parsed from their id.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Property::LastStatus => "last-status",
+            Property::ProtocolVersion => "protocol-version",
+            Property::NcpVersion => "ncp-version",
+            Property::InterfaceType => "interface-type",
+            Property::Caps => "caps",
+            Property::Stream(PropertyStream::Debug) => "stream-debug",
+            Property::Stream(PropertyStream::Raw) => "stream-raw",
+            Property::Stream(PropertyStream::Net) => "stream-net",
+            Property::Stream(PropertyStream::NetInsecure) => "stream-net-insecure",
+            Property::Stream(PropertyStream::Log) => "stream-log",
+            Property::HardwareAddress => "hwaddr",
+            Property::PowerState => "power-state",
+            Property::McuPowerState => "mcu-power-state",
+            Property::HostPowerState => "host-power-state",
+            Property::PhyChannel => "phy-chan",
+            Property::PhyChanSupported => "phy-chan-supported",
+            Property::PhyTxPower => "phy-tx-power",
+            Property::PhyRssi => "phy-rssi",
+            Property::MacScanState => "mac-scan-state",
+            Property::MacScanMask => "mac-scan-mask",
+            Property::MacScanPeriod => "mac-scan-period",
+            Property::MacEnergyScanResult => "mac-energy-scan-result",
+            Property::MacScanBeacon => "mac-scan-beacon",
+            Property::MacDataPollPeriod => "mac-data-poll-period",
+            Property::Mac15_4LAddr => "mac-15-4-laddr",
+            Property::Mac15_4SAddr => "mac-15-4-saddr",
+            Property::Mac15_4PanId => "mac-15-4-panid",
+            Property::Mac15_4ExtendedAddr => "mac-extended-addr",
+            Property::NetIfUp => "net-if-up",
+            Property::NetStackUp => "net-stack-up",
+            Property::NetRole => "net-role",
+            Property::NetNetworkName => "net-network-name",
+            Property::NetXpanId => "net-xpanid",
+            Property::NetNetworkKey => "net-network-key",
+            Property::NetKeySequenceCounter => "net-key-sequence-counter",
+            Property::NetPartitionId => "net-partition-id",
+            Property::CntrReset => "cntr-reset",
+            Property::CntrTxPktTotal => "cntr-tx-pkt-total",
+            Property::CntrTxPktAcked => "cntr-tx-pkt-acked",
+            Property::CntrTxPktRetry => "cntr-tx-pkt-retry",
+            Property::CntrTxErrCca => "cntr-tx-err-cca",
+            Property::CntrTxErrAbort => "cntr-tx-err-abort",
+            Property::CntrRxPktTotal => "cntr-rx-pkt-total",
+            Property::CntrRxErrBadFcs => "cntr-rx-err-bad-fcs",
+            Property::CntrRxErrOther => "cntr-rx-err-other",
+            Property::CntrRxPktDup => "cntr-rx-pkt-dup",
+            Property::Vendor(_) => "vendor",
         }
     }
 
@@ -129,12 +644,49 @@ impl TryFrom<u32> for Property {
             Self::PROP_PROTOCOL_VERSION => Ok(Property::ProtocolVersion),
             Self::PROP_NCP_VERSION => Ok(Property::NcpVersion),
             Self::PROP_INTERFACE_TYPE => Ok(Property::InterfaceType),
+            Self::PROP_CAPS => Ok(Property::Caps),
             Self::PROP_STREAM_DEBUG => Ok(Property::Stream(PropertyStream::Debug)),
+            Self::PROP_STREAM_RAW => Ok(Property::Stream(PropertyStream::Raw)),
             Self::PROP_STREAM_NET => Ok(Property::Stream(PropertyStream::Net)),
             Self::PROP_STREAM_NET_INSECURE => Ok(Property::Stream(PropertyStream::NetInsecure)),
             Self::PROP_STREAM_LOG => Ok(Property::Stream(PropertyStream::Log)),
+            Self::PROP_POWER_STATE => Ok(Property::PowerState),
             Self::PROP_HWADDR => Ok(Property::HardwareAddress),
-            Self::PROP_PHY_TX_POWER => Ok(Property::PhysicalTxPower),
+            Self::PROP_HOST_POWER_STATE => Ok(Property::HostPowerState),
+            Self::PROP_MCU_POWER_STATE => Ok(Property::McuPowerState),
+            Self::PROP_PHY_CHAN => Ok(Property::PhyChannel),
+            Self::PROP_PHY_CHAN_SUPPORTED => Ok(Property::PhyChanSupported),
+            Self::PROP_PHY_TX_POWER => Ok(Property::PhyTxPower),
+            Self::PROP_PHY_RSSI => Ok(Property::PhyRssi),
+            Self::PROP_MAC_SCAN_STATE => Ok(Property::MacScanState),
+            Self::PROP_MAC_SCAN_MASK => Ok(Property::MacScanMask),
+            Self::PROP_MAC_SCAN_PERIOD => Ok(Property::MacScanPeriod),
+            Self::PROP_MAC_ENERGY_SCAN_RESULT => Ok(Property::MacEnergyScanResult),
+            Self::PROP_MAC_SCAN_BEACON => Ok(Property::MacScanBeacon),
+            Self::PROP_MAC_DATA_POLL_PERIOD => Ok(Property::MacDataPollPeriod),
+            Self::PROP_MAC_15_4_LADDR => Ok(Property::Mac15_4LAddr),
+            Self::PROP_MAC_15_4_SADDR => Ok(Property::Mac15_4SAddr),
+            Self::PROP_MAC_15_4_PANID => Ok(Property::Mac15_4PanId),
+            Self::PROP_MAC_EXTENDED_ADDR => Ok(Property::Mac15_4ExtendedAddr),
+            Self::PROP_NET_IF_UP => Ok(Property::NetIfUp),
+            Self::PROP_NET_STACK_UP => Ok(Property::NetStackUp),
+            Self::PROP_NET_ROLE => Ok(Property::NetRole),
+            Self::PROP_NET_NETWORK_NAME => Ok(Property::NetNetworkName),
+            Self::PROP_NET_XPANID => Ok(Property::NetXpanId),
+            Self::PROP_NET_NETWORK_KEY => Ok(Property::NetNetworkKey),
+            Self::PROP_NET_KEY_SEQUENCE_COUNTER => Ok(Property::NetKeySequenceCounter),
+            Self::PROP_NET_PARTITION_ID => Ok(Property::NetPartitionId),
+            Self::PROP_CNTR_RESET => Ok(Property::CntrReset),
+            Self::PROP_CNTR_TX_PKT_TOTAL => Ok(Property::CntrTxPktTotal),
+            Self::PROP_CNTR_TX_PKT_ACKED => Ok(Property::CntrTxPktAcked),
+            Self::PROP_CNTR_TX_PKT_RETRY => Ok(Property::CntrTxPktRetry),
+            Self::PROP_CNTR_TX_ERR_CCA => Ok(Property::CntrTxErrCca),
+            Self::PROP_CNTR_TX_ERR_ABORT => Ok(Property::CntrTxErrAbort),
+            Self::PROP_CNTR_RX_PKT_TOTAL => Ok(Property::CntrRxPktTotal),
+            Self::PROP_CNTR_RX_ERR_BAD_FCS => Ok(Property::CntrRxErrBadFcs),
+            Self::PROP_CNTR_RX_ERR_OTHER => Ok(Property::CntrRxErrOther),
+            Self::PROP_CNTR_RX_PKT_DUP => Ok(Property::CntrRxPktDup),
+            Self::PROP_VENDOR_BEGIN..Self::PROP_VENDOR_END => Ok(Property::Vendor(id)),
             _ => Err(Error::Property(id)),
         }
     }
@@ -146,7 +698,287 @@ impl TryFrom<&[u8]> for Property {
     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
         use crate::codec::PackedU32;
         let len = PackedU32::count_bytes(bytes);
+
+        // A property id that runs off the end of the buffer is truncated
+        if len == 0 {
+            return Err(Error::PacketLength(bytes.len()));
+        }
+
         let prop_id = PackedU32::decode(&bytes[..len]).0;
         Property::try_from(prop_id)
     }
 }
+
+impl FromStr for Property {
+    type Err = Error;
+
+    /// Parse a property from its [`Property::name`], ignoring case, or from its id in decimal or `0x` prefixed hex.
+    ///
+    /// Unknown names return [`Error::PropertyName`], ids that are not known return [`Error::Property`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(prop) = Self::NAMED
+            .iter()
+            .find(|prop| prop.name().eq_ignore_ascii_case(s))
+        {
+            return Ok(prop.clone());
+        }
+
+        let id = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => s.parse(),
+        }
+        .map_err(|_| Error::PropertyName)?;
+
+        Property::try_from(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that each property maps to its wire id and back.
+    fn assert_ids(props: &[(u32, Property)]) {
+        for (id, prop) in props.iter() {
+            assert_eq!(prop.id(), *id);
+            assert_eq!(Property::try_from(*id), Ok(prop.clone()));
+        }
+    }
+
+    #[test]
+    fn core_property_ids() {
+        assert_ids(&[
+            (0x00, Property::LastStatus),
+            (0x01, Property::ProtocolVersion),
+            (0x02, Property::NcpVersion),
+            (0x03, Property::InterfaceType),
+            (0x05, Property::Caps),
+            (0x07, Property::PowerState),
+            (0x08, Property::HardwareAddress),
+            (0x0C, Property::HostPowerState),
+            (0x0D, Property::McuPowerState),
+        ]);
+    }
+
+    #[test]
+    fn phy_property_ids() {
+        assert_ids(&[
+            (0x21, Property::PhyChannel),
+            (0x22, Property::PhyChanSupported),
+            (0x25, Property::PhyTxPower),
+            (0x26, Property::PhyRssi),
+        ]);
+    }
+
+    #[test]
+    fn mac_property_ids() {
+        assert_ids(&[
+            (0x30, Property::MacScanState),
+            (0x31, Property::MacScanMask),
+            (0x32, Property::MacScanPeriod),
+            (0x33, Property::MacScanBeacon),
+            (0x34, Property::Mac15_4LAddr),
+            (0x35, Property::Mac15_4SAddr),
+            (0x36, Property::Mac15_4PanId),
+            (0x39, Property::MacEnergyScanResult),
+            (0x3A, Property::MacDataPollPeriod),
+            (0x1302, Property::Mac15_4ExtendedAddr),
+        ]);
+    }
+
+    #[test]
+    fn net_property_ids() {
+        assert_ids(&[
+            (0x41, Property::NetIfUp),
+            (0x42, Property::NetStackUp),
+            (0x43, Property::NetRole),
+            (0x44, Property::NetNetworkName),
+            (0x45, Property::NetXpanId),
+            (0x46, Property::NetNetworkKey),
+            (0x47, Property::NetKeySequenceCounter),
+            (0x48, Property::NetPartitionId),
+        ]);
+    }
+
+    #[test]
+    fn counter_property_ids() {
+        assert_ids(&[
+            (0x500, Property::CntrReset),
+            (0x501, Property::CntrTxPktTotal),
+            (0x503, Property::CntrTxPktAcked),
+            (0x50A, Property::CntrTxPktRetry),
+            (0x50B, Property::CntrTxErrCca),
+            (0x50E, Property::CntrTxErrAbort),
+            (0x564, Property::CntrRxPktTotal),
+            (0x570, Property::CntrRxErrBadFcs),
+            (0x571, Property::CntrRxErrOther),
+            (0x572, Property::CntrRxPktDup),
+        ]);
+    }
+
+    #[test]
+    fn vendor_property_ids() {
+        assert_ids(&[
+            (0x3C00, Property::Vendor(0x3C00)),
+            (0x3FFF, Property::Vendor(0x3FFF)),
+        ]);
+        assert_eq!(Property::try_from(0x4000), Err(Error::Property(0x4000)));
+    }
+
+    #[test]
+    fn stream_property_ids() {
+        assert_ids(&[
+            (0x70, Property::Stream(PropertyStream::Debug)),
+            (0x71, Property::Stream(PropertyStream::Raw)),
+            (0x72, Property::Stream(PropertyStream::Net)),
+            (0x73, Property::Stream(PropertyStream::NetInsecure)),
+            (0x74, Property::Stream(PropertyStream::Log)),
+        ]);
+    }
+
+    #[test]
+    fn stream_is_writable() {
+        assert!(!Property::Stream(PropertyStream::Debug).is_writable());
+        assert!(Property::Stream(PropertyStream::Raw).is_writable());
+        assert!(Property::Stream(PropertyStream::Net).is_writable());
+        assert!(Property::Stream(PropertyStream::NetInsecure).is_writable());
+        assert!(!Property::Stream(PropertyStream::Log).is_writable());
+    }
+
+    #[test]
+    fn read_only_properties() {
+        assert!(!Property::LastStatus.is_writable());
+        assert!(!Property::CntrTxPktTotal.is_writable());
+        assert!(Property::CntrReset.is_writable());
+        assert!(Property::NetIfUp.is_writable());
+    }
+
+    #[test]
+    fn classify_property_ranges() {
+        for stream in [
+            PropertyStream::Debug,
+            PropertyStream::Raw,
+            PropertyStream::Net,
+            PropertyStream::NetInsecure,
+            PropertyStream::Log,
+        ] {
+            assert!(Property::Stream(stream).is_stream());
+        }
+
+        assert!(Property::PhyChannel.is_phy());
+        assert!(Property::PhyRssi.is_phy());
+        assert!(Property::MacScanState.is_mac());
+        assert!(Property::MacEnergyScanResult.is_mac());
+        assert!(Property::Mac15_4ExtendedAddr.is_mac());
+        assert!(Property::NetIfUp.is_net());
+        assert!(Property::NetPartitionId.is_net());
+
+        assert!(!Property::LastStatus.is_stream());
+        assert!(!Property::PhyChannel.is_mac());
+        assert!(!Property::NetRole.is_thread());
+        assert!(!Property::CntrReset.is_net());
+        assert!(!Property::Vendor(0x3C00).is_phy());
+    }
+
+    #[test]
+    fn fixed_length_properties() {
+        assert_eq!(Property::NetIfUp.fixed_len(), Some(1));
+        assert_eq!(Property::Mac15_4PanId.fixed_len(), Some(2));
+        assert_eq!(Property::MacDataPollPeriod.fixed_len(), Some(4));
+        assert_eq!(Property::Mac15_4ExtendedAddr.fixed_len(), Some(8));
+        assert_eq!(Property::NetNetworkKey.fixed_len(), Some(16));
+
+        assert_eq!(Property::LastStatus.fixed_len(), None);
+        assert_eq!(Property::NcpVersion.fixed_len(), None);
+        assert_eq!(Property::Stream(PropertyStream::Net).fixed_len(), None);
+        assert_eq!(Property::Vendor(0x3C00).fixed_len(), None);
+    }
+
+    #[test]
+    fn decode_network_name() {
+        use crate::{codec::Decode, Command};
+        use bytes::Bytes;
+
+        let bytes = Bytes::from_static(b"\x06\x44OpenThread\0");
+        let Ok(Command::PropertyValueIs(Property::NetNetworkName, value)) = Command::decode(&bytes)
+        else {
+            panic!("failed to decode network name");
+        };
+
+        assert_eq!(<&str>::decode(&value), Ok(("OpenThread", 11)));
+    }
+
+    #[test]
+    fn decode_multi_byte_property_id() {
+        let prop = Property::try_from(&[0x82, 0x26][..]);
+        assert_eq!(prop, Ok(Property::Mac15_4ExtendedAddr));
+        assert_eq!(Property::Mac15_4ExtendedAddr.packed_len(), 2);
+    }
+
+    #[test]
+    fn unknown_property_id() {
+        assert_eq!(Property::try_from(0x7F), Err(Error::Property(0x7F)));
+    }
+
+    #[test]
+    fn every_known_id_has_a_name() {
+        for id in 0..Property::PROP_VENDOR_BEGIN {
+            if let Ok(prop) = Property::try_from(id) {
+                assert!(Property::NAMED.contains(&prop), "{prop} is not named");
+                assert_eq!(prop.name().parse(), Ok(prop));
+            }
+        }
+    }
+
+    #[test]
+    fn parse_property_names() {
+        assert_eq!("ncp-version".parse(), Ok(Property::NcpVersion));
+        assert_eq!("NET-IF-UP".parse(), Ok(Property::NetIfUp));
+        assert_eq!(
+            "stream-net-insecure".parse(),
+            Ok(Property::Stream(PropertyStream::NetInsecure))
+        );
+        assert_eq!(
+            "stream-raw".parse(),
+            Ok(Property::Stream(PropertyStream::Raw))
+        );
+        assert_eq!("phy-chan".parse(), Ok(Property::PhyChannel));
+        assert_eq!(Property::Vendor(0x3C01).name(), "vendor");
+    }
+
+    #[test]
+    fn parse_property_ids() {
+        assert_eq!("0x02".parse(), Ok(Property::NcpVersion));
+        assert_eq!("0X72".parse(), Ok(Property::Stream(PropertyStream::Net)));
+        assert_eq!("48".parse(), Ok(Property::MacScanState));
+        assert_eq!("0x3c01".parse(), Ok(Property::Vendor(0x3C01)));
+        assert_eq!("0x7f".parse::<Property>(), Err(Error::Property(0x7F)));
+    }
+
+    #[test]
+    fn parse_unknown_property_name() {
+        assert_eq!("channel".parse::<Property>(), Err(Error::PropertyName));
+        assert_eq!("vendor".parse::<Property>(), Err(Error::PropertyName));
+        assert_eq!("0x".parse::<Property>(), Err(Error::PropertyName));
+    }
+
+    #[test]
+    fn properties_as_set_keys() {
+        use std::collections::HashSet;
+
+        let props: HashSet<Property> = [
+            Property::NcpVersion,
+            Property::ProtocolVersion,
+            Property::Stream(PropertyStream::Net),
+            Property::Vendor(0x3C00),
+            Property::NcpVersion,
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(props.len(), 4);
+        assert!(props.contains(&Property::Stream(PropertyStream::Net)));
+        assert!(!props.contains(&Property::Stream(PropertyStream::Debug)));
+        assert!(!props.contains(&Property::Vendor(0x3C01)));
+    }
+}
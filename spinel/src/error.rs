@@ -1,4 +1,7 @@
-use crate::{Frame, Status};
+use crate::{
+    codec::{InterfaceType, ProtocolVersion},
+    Frame, Property, Status,
+};
 use platform_switch::thiserror;
 
 cfg_if::cfg_if! {
@@ -22,7 +25,7 @@ cfg_if::cfg_if! {
     }
 }
 
-#[derive(Debug, PartialEq, thiserror::Error)]
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("Unable to parse UTF8 characters")]
     DatatypeParseU8(#[from] core::str::Utf8Error),
@@ -38,20 +41,42 @@ pub enum Error {
     HostConnectionSend,
     #[error("Could not receive message, host connection failure: {0:?}")]
     HostConnectionRecv(HostConnectionRecvError),
+    #[error("Invalid channel: {0}")]
+    Channel(u8),
     #[error("Unknown command: {0}")]
     Command(u32),
+    #[error("Incompatible protocol version: {0}")]
+    IncompatibleProtocol(ProtocolVersion),
+    #[error("Invalid instance identifier: {0}")]
+    InstanceId(u8),
+    #[error("Invalid hex on line {0}")]
+    InvalidHex(usize),
     #[error("IO Error: {0:?}")]
     Io(IoError),
     #[error("Unknown property: {0}")]
     Property(u32),
+    #[error("Unknown property name")]
+    PropertyName,
+    #[error("Invalid value for property: {0}")]
+    PropertyValue(Property),
     #[error("Invalid number of bytes for a packed integer")]
     PackedU32ByteCount,
+    #[error("Format string does not match the values")]
+    PackFormat,
     #[error("Incorrect packet length: {0}")]
     PacketLength(usize),
     #[error("Error configuring serial port")]
     SerialConfig,
+    #[error("Invalid SPI header: {0}")]
+    SpiHeader(u8),
     #[error("Target status: {0}")]
     Status(Status),
+    #[error("Invalid transaction identifier: {0}")]
+    TransactionId(u8),
+    #[error("Unknown power state: {0}")]
+    UnknownPowerState(u8),
     #[error("Target sent unexpected response: {0:?}")]
     UnexpectedResponse(Frame),
+    #[error("Target has the wrong interface type: {0}")]
+    WrongInterface(InterfaceType),
 }
@@ -1,11 +1,24 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
+pub mod capture;
 pub mod codec;
 mod error;
+#[cfg(all(feature = "std", any(test, feature = "test-util")))]
+pub mod replay;
+#[cfg(feature = "std")]
+pub mod serial;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_frames;
 
 #[cfg(feature = "std")]
-pub use codec::HdlcCodec;
+pub use capture::{LinkType, PcapWriter};
 pub use codec::{
-    Command, Frame, HdlcLiteFrame, Header, PackedU32, Property, PropertyStream, Status,
+    Command, CommandRef, CrcMode, Frame, FrameAccumulator, FrameRef, HdlcLiteFrame, Header,
+    InstanceId, PackedI32, PackedU32, Property, PropertyStream, ResetReason, Status,
 };
+#[cfg(feature = "std")]
+pub use codec::{DebugLines, DecodedItem, HdlcCodec, HdlcPassthroughCodec};
 pub use error::Error;
+#[cfg(feature = "std")]
+pub use serial::{FlowControl, ResetLines, SerialConfig};
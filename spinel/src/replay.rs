@@ -0,0 +1,102 @@
+//! Replay a recorded session of [`Frame`]s, for reproducing field captures offline.
+use crate::{codec::HexDump, Error, Frame};
+use bytes::Bytes;
+use std::io::{BufRead, BufReader, Lines, Read};
+
+/// Read [`Frame`]s from a recording with one frame per line.
+///
+/// Each line holds the wire bytes of one frame as hex, the format produced by [`Frame::hexdump`], for example
+/// `81 06 00 00`. Whitespace between bytes is optional. Empty lines and lines starting with `#` are skipped.
+///
+/// The source is an [`Iterator`], use `futures::stream::iter` to feed it to code that expects a `Stream`.
+#[derive(Debug)]
+pub struct ReplaySource<R: Read> {
+    lines: Lines<BufReader<R>>,
+    line: usize,
+}
+
+impl<R: Read> ReplaySource<R> {
+    /// Create a new [`ReplaySource`] reading from `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: BufReader::new(reader).lines(),
+            line: 0,
+        }
+    }
+
+    /// Parse a line of hex into bytes, returning [`Error::InvalidHex`] with the line number if it is malformed.
+    fn parse_line(&self, line: &str) -> Result<Bytes, Error> {
+        HexDump::parse(line)
+            .map(HexDump::into_bytes)
+            .ok_or(Error::InvalidHex(self.line))
+    }
+}
+
+impl<R: Read> Iterator for ReplaySource<R> {
+    type Item = Result<Frame, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line += 1;
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            return Some(
+                self.parse_line(line)
+                    .and_then(|bytes| Frame::decode(&bytes)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_frames, Command, Header};
+
+    #[test]
+    fn replays_two_frames() {
+        let recording = b"# reset and noop\n81 06 00 00\n\n8100\n";
+        let frames: Vec<_> = ReplaySource::new(&recording[..]).collect();
+
+        assert_eq!(
+            frames,
+            vec![
+                Ok(test_frames::noop_reply(1)),
+                Ok(Frame::new(Header::new(0, 1), Command::Noop)),
+            ]
+        );
+    }
+
+    #[test]
+    fn replays_hexdump_output() {
+        let frame = test_frames::ncp_version(2, "OPENTHREAD/1.0");
+        let recording = format!("{}\n", frame.hexdump().unwrap());
+
+        let mut source = ReplaySource::new(recording.as_bytes());
+        assert_eq!(source.next(), Some(Ok(frame)));
+        assert_eq!(source.next(), None);
+    }
+
+    #[test]
+    fn reports_line_of_invalid_hex() {
+        let recording = b"81 00\n\n81 0\n81 zz\n";
+        let frames: Vec<_> = ReplaySource::new(&recording[..]).collect();
+
+        assert_eq!(
+            frames,
+            vec![
+                Ok(Frame::new(Header::new(0, 1), Command::Noop)),
+                Err(Error::InvalidHex(3)),
+                Err(Error::InvalidHex(4)),
+            ]
+        );
+    }
+}
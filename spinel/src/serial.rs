@@ -0,0 +1,297 @@
+//! Configuration for opening a serial port to a Spinel device.
+use crate::Error;
+use core::str::FromStr;
+use std::time::Duration;
+use tokio_serial::{
+    DataBits, Parity, SerialPort, SerialPortBuilder, SerialPortBuilderExt, SerialStream, StopBits,
+};
+
+/// Flow control used on the serial port.
+///
+/// Many devices drop bytes at high baud rates without hardware (RTS/CTS) flow control, which shows up as HDLC
+/// framing and checksum errors.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FlowControl {
+    /// No flow control.
+    #[default]
+    None,
+
+    /// Software flow control using XON/XOFF bytes.
+    Software,
+
+    /// Hardware flow control using the RTS/CTS lines.
+    Hardware,
+}
+
+impl FromStr for FlowControl {
+    type Err = Error;
+
+    /// Parse the flow control from its name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(FlowControl::None),
+            "software" => Ok(FlowControl::Software),
+            "hardware" => Ok(FlowControl::Hardware),
+            _ => Err(Error::SerialConfig),
+        }
+    }
+}
+
+impl From<FlowControl> for tokio_serial::FlowControl {
+    fn from(flow_control: FlowControl) -> Self {
+        match flow_control {
+            FlowControl::None => tokio_serial::FlowControl::None,
+            FlowControl::Software => tokio_serial::FlowControl::Software,
+            FlowControl::Hardware => tokio_serial::FlowControl::Hardware,
+        }
+    }
+}
+
+/// Serial control lines pulsed to reset the device, see [`SerialConfig::hardware_reset`].
+///
+/// Some boards wire DTR and/or RTS to the reset pin of the device, which is more reliable than
+/// [`Command::Reset`](crate::Command::Reset) when the firmware is unresponsive.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ResetLines {
+    /// The device cannot be reset from the serial port.
+    #[default]
+    None,
+
+    /// Pulse the DTR line.
+    Dtr,
+
+    /// Pulse the RTS line.
+    Rts,
+
+    /// Pulse both the DTR and RTS lines together.
+    Both,
+}
+
+impl ResetLines {
+    fn dtr(&self) -> bool {
+        matches!(self, ResetLines::Dtr | ResetLines::Both)
+    }
+
+    fn rts(&self) -> bool {
+        matches!(self, ResetLines::Rts | ResetLines::Both)
+    }
+}
+
+impl FromStr for ResetLines {
+    type Err = Error;
+
+    /// Parse the reset lines from their name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(ResetLines::None),
+            "dtr" => Ok(ResetLines::Dtr),
+            "rts" => Ok(ResetLines::Rts),
+            "both" => Ok(ResetLines::Both),
+            _ => Err(Error::SerialConfig),
+        }
+    }
+}
+
+/// Settings used to open the serial port to a device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SerialConfig {
+    /// System name of the port, for example `/dev/ttyACM0`.
+    pub port_name: String,
+
+    /// Baud rate of the port.
+    pub baud_rate: u32,
+
+    /// Flow control used on the port.
+    pub flow_control: FlowControl,
+
+    /// Number of data bits in each character.
+    pub data_bits: DataBits,
+
+    /// Parity used to check each character.
+    pub parity: Parity,
+
+    /// Number of stop bits after each character.
+    pub stop_bits: StopBits,
+
+    /// Control lines pulsed by [`SerialConfig::hardware_reset`].
+    pub reset_lines: ResetLines,
+
+    /// How long the control lines are held during [`SerialConfig::hardware_reset`].
+    pub reset_pulse: Duration,
+}
+
+impl SerialConfig {
+    /// Default time the control lines are held for a hardware reset.
+    pub const DEFAULT_RESET_PULSE: Duration = Duration::from_millis(100);
+
+    /// Create a new [`SerialConfig`] using 8N1 framing without flow control or hardware reset.
+    pub fn new(port_name: &str, baud_rate: u32) -> Self {
+        Self {
+            port_name: port_name.to_string(),
+            baud_rate,
+            flow_control: FlowControl::default(),
+            data_bits: DataBits::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+            reset_lines: ResetLines::default(),
+            reset_pulse: Self::DEFAULT_RESET_PULSE,
+        }
+    }
+
+    /// Set the flow control used on the port.
+    pub fn with_flow_control(mut self, flow_control: FlowControl) -> Self {
+        self.flow_control = flow_control;
+        self
+    }
+
+    /// Set the number of data bits in each character.
+    pub fn with_data_bits(mut self, data_bits: DataBits) -> Self {
+        self.data_bits = data_bits;
+        self
+    }
+
+    /// Set the parity used to check each character.
+    pub fn with_parity(mut self, parity: Parity) -> Self {
+        self.parity = parity;
+        self
+    }
+
+    /// Set the number of stop bits after each character.
+    pub fn with_stop_bits(mut self, stop_bits: StopBits) -> Self {
+        self.stop_bits = stop_bits;
+        self
+    }
+
+    /// Set the control lines pulsed to reset the device.
+    pub fn with_reset_lines(mut self, reset_lines: ResetLines) -> Self {
+        self.reset_lines = reset_lines;
+        self
+    }
+
+    /// Set how long the control lines are held to reset the device.
+    pub fn with_reset_pulse(mut self, reset_pulse: Duration) -> Self {
+        self.reset_pulse = reset_pulse;
+        self
+    }
+
+    /// Create a [`SerialPortBuilder`] with the settings applied.
+    pub fn builder(&self) -> SerialPortBuilder {
+        tokio_serial::new(&self.port_name, self.baud_rate)
+            .flow_control(self.flow_control.into())
+            .data_bits(self.data_bits)
+            .parity(self.parity)
+            .stop_bits(self.stop_bits)
+    }
+
+    /// Open the serial port.
+    pub fn open(&self) -> Result<SerialStream, Error> {
+        self.builder()
+            .open_native_async()
+            .map_err(|e| Error::from(std::io::Error::from(e)))
+    }
+
+    /// Reset the device by asserting the configured control lines for the reset pulse, then releasing them.
+    ///
+    /// Does nothing if the reset lines are [`ResetLines::None`]. Like [`Command::Reset`](crate::Command::Reset),
+    /// the device reports the reset with a [`Property::LastStatus`](crate::Property::LastStatus) once it boots.
+    pub async fn hardware_reset(&self, port: &mut SerialStream) -> Result<(), Error> {
+        if self.reset_lines == ResetLines::None {
+            return Ok(());
+        }
+
+        self.write_reset_lines(port, true)?;
+        tokio::time::sleep(self.reset_pulse).await;
+        self.write_reset_lines(port, false)
+    }
+
+    /// Set the level of the configured control lines.
+    fn write_reset_lines(&self, port: &mut SerialStream, level: bool) -> Result<(), Error> {
+        let map_err = |e: tokio_serial::Error| Error::from(std::io::Error::from(e));
+
+        if self.reset_lines.dtr() {
+            port.write_data_terminal_ready(level).map_err(map_err)?;
+        }
+        if self.reset_lines.rts() {
+            port.write_request_to_send(level).map_err(map_err)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_flow_control() {
+        assert_eq!("none".parse(), Ok(FlowControl::None));
+        assert_eq!("Software".parse(), Ok(FlowControl::Software));
+        assert_eq!("HARDWARE".parse(), Ok(FlowControl::Hardware));
+        assert_eq!("rts".parse::<FlowControl>(), Err(Error::SerialConfig));
+    }
+
+    #[test]
+    fn parse_reset_lines() {
+        assert_eq!("none".parse(), Ok(ResetLines::None));
+        assert_eq!("DTR".parse(), Ok(ResetLines::Dtr));
+        assert_eq!("rts".parse(), Ok(ResetLines::Rts));
+        assert_eq!("Both".parse(), Ok(ResetLines::Both));
+        assert_eq!("cts".parse::<ResetLines>(), Err(Error::SerialConfig));
+    }
+
+    #[test]
+    fn config_with_reset_lines() {
+        let config = SerialConfig::new("/dev/ttyACM0", 115_200);
+        assert_eq!(config.reset_lines, ResetLines::None);
+        assert_eq!(config.reset_pulse, SerialConfig::DEFAULT_RESET_PULSE);
+
+        let config = config
+            .with_reset_lines(ResetLines::Both)
+            .with_reset_pulse(Duration::from_millis(20));
+        assert_eq!(config.reset_lines, ResetLines::Both);
+        assert_eq!(config.reset_pulse, Duration::from_millis(20));
+        assert!(config.reset_lines.dtr() && config.reset_lines.rts());
+        assert!(!ResetLines::Rts.dtr());
+    }
+
+    #[test]
+    fn config_defaults_to_8n1() {
+        let config = SerialConfig::new("/dev/ttyACM0", 115_200);
+        assert_eq!(
+            config.builder(),
+            tokio_serial::new("/dev/ttyACM0", 115_200)
+                .flow_control(tokio_serial::FlowControl::None)
+                .data_bits(DataBits::Eight)
+                .parity(Parity::None)
+                .stop_bits(StopBits::One)
+        );
+    }
+
+    #[test]
+    fn config_applies_framing() {
+        let config = SerialConfig::new("/dev/ttyACM0", 115_200)
+            .with_data_bits(DataBits::Seven)
+            .with_parity(Parity::Even)
+            .with_stop_bits(StopBits::Two);
+        assert_eq!(
+            config.builder(),
+            tokio_serial::new("/dev/ttyACM0", 115_200)
+                .flow_control(tokio_serial::FlowControl::None)
+                .data_bits(DataBits::Seven)
+                .parity(Parity::Even)
+                .stop_bits(StopBits::Two)
+        );
+    }
+
+    #[test]
+    fn config_with_flow_control() {
+        let config =
+            SerialConfig::new("/dev/ttyACM0", 460_800).with_flow_control(FlowControl::Hardware);
+        assert_eq!(config.flow_control, FlowControl::Hardware);
+        assert_eq!(
+            tokio_serial::FlowControl::from(config.flow_control),
+            tokio_serial::FlowControl::Hardware
+        );
+    }
+}
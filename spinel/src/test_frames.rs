@@ -0,0 +1,93 @@
+//! Factories for realistic device-to-host [`Frame`]s, useful for seeding tests without hand-encoding bytes.
+use crate::{codec::PackedU32, Command, Frame, Header, Property, ResetReason, Status};
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// The device reply to a [`Command::Noop`] request: a [`Status::Ok`] [`Property::LastStatus`].
+pub fn noop_reply(tid: u8) -> Frame {
+    last_status(tid, Status::Ok)
+}
+
+/// A [`Property::LastStatus`] reply carrying `status`.
+pub fn last_status(tid: u8, status: Status) -> Frame {
+    Frame::new(
+        Header::new(0, tid),
+        Command::PropertyValueIs(Property::LastStatus, status_value(u32::from(status))),
+    )
+}
+
+/// The device reply to a [`Property::NcpVersion`] request.
+///
+/// The version string is NUL terminated on the wire, just like a real device sends it.
+pub fn ncp_version(tid: u8, version: &str) -> Frame {
+    let mut value = BytesMut::with_capacity(version.len() + 1);
+    value.put_slice(version.as_bytes());
+    value.put_u8(0);
+
+    Frame::new(
+        Header::new(0, tid),
+        Command::PropertyValueIs(Property::NcpVersion, value.freeze()),
+    )
+}
+
+/// The unsolicited [`Property::LastStatus`] a device broadcasts after it resets.
+pub fn reset_broadcast(reason: ResetReason) -> Frame {
+    Frame::new(
+        Header::new(0, 0),
+        Command::PropertyValueIs(Property::LastStatus, status_value(u32::from(reason))),
+    )
+}
+
+/// Encode a [`Property::LastStatus`] value, which is a packed `uint32` on the wire.
+fn status_value(code: u32) -> Bytes {
+    let mut value = BytesMut::with_capacity(PackedU32::packed_len(code));
+    PackedU32::write_to_buffer(code, &mut value);
+    value.freeze()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(frame: Frame) -> BytesMut {
+        let mut buffer = BytesMut::new();
+        frame.encode(&mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn noop_reply_wire_format() {
+        assert_eq!(encode(noop_reply(1)), &[0x81, 0x06, 0x00, 0x00][..]);
+    }
+
+    #[test]
+    fn reserved_status_is_packed() {
+        let frame = last_status(3, Status::Reserved(0x80));
+        assert_eq!(encode(frame.clone()), &[0x83, 0x06, 0x00, 0x80, 0x01][..]);
+        assert_eq!(frame.validate(), Ok(()));
+        assert_eq!(frame.last_status(), Some(Ok(Status::Reserved(0x80))));
+
+        let frame = last_status(3, Status::Vendor(0x3C00));
+        assert_eq!(encode(frame.clone()), &[0x83, 0x06, 0x00, 0x80, 0x78][..]);
+        assert_eq!(frame.last_status(), Some(Ok(Status::Vendor(0x3C00))));
+    }
+
+    #[test]
+    fn ncp_version_wire_format() {
+        assert_eq!(
+            encode(ncp_version(2, "OT")),
+            &[0x82, 0x06, 0x02, b'O', b'T', 0x00][..]
+        );
+    }
+
+    #[test]
+    fn reset_broadcast_wire_format() {
+        assert_eq!(
+            encode(reset_broadcast(ResetReason::PowerOn)),
+            &[0x80, 0x06, 0x00, 0x70][..]
+        );
+        assert_eq!(
+            encode(reset_broadcast(ResetReason::PlatformSpecific(0x3C01))),
+            &[0x80, 0x06, 0x00, 0x81, 0x78][..]
+        );
+    }
+}